@@ -3,6 +3,7 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use word_bounds::impls::charwalk::Charwalk;
 use word_bounds::impls::fancy_regex::FancyRegex;
 use word_bounds::impls::regex::Regex;
+use word_bounds::impls::unicode::UnicodeWords;
 use word_bounds::resolver::WordBoundResolver;
 use word_bounds::rules::DefaultRules;
 
@@ -18,6 +19,10 @@ fn word_bounds_charwalk(s: &str) -> Vec<String> {
     WordBoundResolver::<Charwalk, DefaultRules>::resolve(s)
 }
 
+fn word_bounds_unicode(s: &str) -> Vec<String> {
+    WordBoundResolver::<UnicodeWords, DefaultRules>::resolve(s)
+}
+
 fn criterion_benchmark(c: &mut Criterion) {
     let input = "This_is_SomeRandom_Text-to-split2";
 
@@ -33,6 +38,10 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("word_bounds_charwalk", |b| {
         b.iter(|| word_bounds_charwalk(black_box(input)))
     });
+
+    c.bench_function("word_bounds_unicode", |b| {
+        b.iter(|| word_bounds_unicode(black_box(input)))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
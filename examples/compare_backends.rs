@@ -3,13 +3,16 @@
 //! The crate offers "varying implementations to choose from", so what they do differently
 //! is the thing a chooser needs to know. This prints it.
 //!
-//! Run with: cargo run --example compare_backends --features use_regex,use_fancy_regex
+//! Run with:
+//! cargo run --example compare_backends --features use_regex,use_fancy_regex,enhanced_accuracy
 
 use word_bounds::impls::charwalk::Charwalk;
 #[cfg(feature = "use_fancy_regex")]
 use word_bounds::impls::fancy_regex::FancyRegex;
 #[cfg(feature = "use_regex")]
 use word_bounds::impls::regex::Regex;
+#[cfg(feature = "enhanced_accuracy")]
+use word_bounds::impls::unicode::UnicodeWords;
 use word_bounds::resolver::WordBoundResolver;
 use word_bounds::rules::DefaultRules;
 
@@ -27,6 +30,8 @@ const CASES: &[&str] = &[
     "snake_case",
     "kebab-case",
     "WordWithNumbers123",
+    "foo—bar",
+    "emoji🎉party",
 ];
 
 fn main() {
@@ -37,5 +42,7 @@ fn main() {
         println!("  regex:       {:?}", WordBoundResolver::<Regex, DefaultRules>::resolve(input));
         #[cfg(feature = "use_fancy_regex")]
         println!("  fancy_regex: {:?}", WordBoundResolver::<FancyRegex, DefaultRules>::resolve(input));
+        #[cfg(feature = "enhanced_accuracy")]
+        println!("  unicode:     {:?}", WordBoundResolver::<UnicodeWords, DefaultRules>::resolve(input));
     }
 }
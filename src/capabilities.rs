@@ -0,0 +1,203 @@
+//! What each backend makes of a ruleset, known before any input is.
+//!
+//! The backends do not read every rule the same way, and some not at all. Charwalk reads them all,
+//! but passes over whole words during resolution, which only exist once it is done. The regex
+//! backends remove fewer kinds of target, read most removals as taking every occurrence, and
+//! refuse a removal they cannot express. [`validate`] lays a ruleset against a backend's
+//! [`Capabilities`] and says which of its rules will not be read as written.
+//!
+//! ```
+//! use word_bounds::capabilities::validate;
+//! use word_bounds::impls::charwalk::Charwalk;
+//! use word_bounds::rules::RuleSet;
+//!
+//! assert!(validate::<Charwalk>(&RuleSet::default()).is_empty());
+//! ```
+
+use crate::error::SegmentationError;
+use crate::rules::{RemoveMode, ResolverProcessingRule, RuleSet, RuleTarget, Scope};
+use crate::WordBoundResolverImpl;
+
+/// A [`RuleTarget`] without what it carries, for naming kinds of target a backend reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetKind {
+    Word,
+    String,
+    Char,
+    Numerics,
+    Acronym,
+    PunctSpecialChar,
+    PunctSpecialCharRun,
+    NonPunctSpecialChar,
+    CaseChangeNonAcronym,
+}
+
+impl TargetKind {
+    /// Every kind of target that is read a character at a time, which is all of them but
+    /// [`Word`](TargetKind::Word).
+    pub const WITHIN_WORDS: &'static [TargetKind] = &[
+        TargetKind::String,
+        TargetKind::Char,
+        TargetKind::Numerics,
+        TargetKind::Acronym,
+        TargetKind::PunctSpecialChar,
+        TargetKind::PunctSpecialCharRun,
+        TargetKind::NonPunctSpecialChar,
+        TargetKind::CaseChangeNonAcronym,
+    ];
+
+    /// The kind of `target`.
+    pub fn of(target: &RuleTarget) -> Self {
+        match target {
+            RuleTarget::Word(_) => TargetKind::Word,
+            RuleTarget::String(_) => TargetKind::String,
+            RuleTarget::Char(_) => TargetKind::Char,
+            RuleTarget::Numerics => TargetKind::Numerics,
+            RuleTarget::Acronym => TargetKind::Acronym,
+            RuleTarget::PunctSpecialChar => TargetKind::PunctSpecialChar,
+            RuleTarget::PunctSpecialCharRun => TargetKind::PunctSpecialCharRun,
+            RuleTarget::NonPunctSpecialChar => TargetKind::NonPunctSpecialChar,
+            RuleTarget::CaseChangeNonAcronym => TargetKind::CaseChangeNonAcronym,
+        }
+    }
+}
+
+/// What a backend reads of the rules resolution applies.
+///
+/// The passes either side of resolution, attachments, and removals within each word or of what
+/// leads or trails the input are read the same by every backend, outside its own resolution, so
+/// nothing here is about them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The name the backend goes by, here and in a [`SegmentationError`].
+    pub backend: &'static str,
+    /// Whether [`iter`](WordBoundResolverImpl::iter) does no more of the input than it is
+    /// advanced through, rather than working out every word before handing out the first.
+    ///
+    /// A backend that walks the input is lazy only while no pass around it needs all of the
+    /// input, so this is the one field that can differ between
+    /// [`capabilities_for`](WordBoundResolverImpl::capabilities_for) one ruleset and another.
+    pub lazy: bool,
+    /// The targets a removal can take.
+    pub removes: &'static [TargetKind],
+    /// The targets a removal reads the mode of. Of any other, every occurrence is removed.
+    pub remove_modes: &'static [TargetKind],
+    /// Whether a removal of a target it cannot take makes the backend refuse the ruleset, rather
+    /// than pass over the rule.
+    pub refuses: bool,
+    /// The targets a `BoundStart` is read of.
+    pub bound_starts: &'static [TargetKind],
+    /// The targets a `BoundEnd` is read of.
+    pub bound_ends: &'static [TargetKind],
+    /// Whether a punctuation run bounded on one side only is left unbounded on the other, rather
+    /// than made a word of its own.
+    pub one_sided_runs: bool,
+    /// Whether the characters of a string answer to the string's rules alone, rather than still
+    /// to the rules about each of them.
+    pub whole_strings: bool,
+}
+
+/// How a backend falls short of a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shortfall {
+    /// The rule is not read, and resolution goes on as if it were not there.
+    Ignored,
+    /// The backend cannot express the rule and refuses the ruleset it is in, with
+    /// [`SegmentationError::UnsupportedRule`].
+    Unsupported,
+    /// The rule is read, but not as it is written; what is done instead.
+    Approximated(&'static str),
+}
+
+/// A rule of a ruleset a backend does not read as written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub rule: ResolverProcessingRule,
+    pub shortfall: Shortfall,
+}
+
+impl Capabilities {
+    /// The rules of `rules` this backend will not read as written, in the order they are listed.
+    pub fn validate(&self, rules: &RuleSet) -> Vec<Finding> {
+        use ResolverProcessingRule::{BoundEnd, BoundStart, Remove};
+        const BOTH_SIDES: Shortfall = Shortfall::Approximated("the run is bounded on both sides");
+        const BY_CHARACTER: Shortfall = Shortfall::Approximated(
+            "the string's characters still answer to the rules about each of them",
+        );
+
+        let listed = |rule: &ResolverProcessingRule| rules.resolution_pass_rules.contains(rule);
+        let mut findings = Vec::new();
+        for rule in &rules.resolution_pass_rules {
+            let Some(kind) = rule.target().map(TargetKind::of) else {
+                continue;
+            };
+            let shortfall = match rule {
+                Remove(_, mode) if !removes_in_resolution(mode) => None,
+                Remove(..) if !self.removes.contains(&kind) => {
+                    Some(if self.refuses { Shortfall::Unsupported } else { Shortfall::Ignored })
+                },
+                Remove(_, mode)
+                    if mode != &RemoveMode::All && !self.remove_modes.contains(&kind) =>
+                {
+                    Some(Shortfall::Approximated("every occurrence is removed, whatever the mode"))
+                },
+                BoundStart(_) if !self.bound_starts.contains(&kind) => Some(Shortfall::Ignored),
+                BoundEnd(_) if !self.bound_ends.contains(&kind) => Some(Shortfall::Ignored),
+                BoundStart(RuleTarget::PunctSpecialCharRun)
+                    if !self.one_sided_runs
+                        && !listed(&BoundEnd(RuleTarget::PunctSpecialCharRun)) =>
+                {
+                    Some(BOTH_SIDES)
+                },
+                BoundEnd(RuleTarget::PunctSpecialCharRun)
+                    if !self.one_sided_runs
+                        && !listed(&BoundStart(RuleTarget::PunctSpecialCharRun)) =>
+                {
+                    Some(BOTH_SIDES)
+                },
+                Remove(RuleTarget::String(_), _)
+                | BoundStart(RuleTarget::String(_))
+                | BoundEnd(RuleTarget::String(_))
+                    if !self.whole_strings =>
+                {
+                    Some(BY_CHARACTER)
+                },
+                _ => None,
+            };
+            if let Some(shortfall) = shortfall {
+                findings.push(Finding { rule: rule.clone(), shortfall });
+            }
+        }
+        findings
+    }
+
+    /// `Ok` unless the backend refuses a rule of `rules`, and then the first it refuses, as
+    /// [`try_prepare`](WordBoundResolverImpl::try_prepare) would.
+    pub fn supported(&self, rules: &RuleSet) -> Result<(), SegmentationError> {
+        let mut findings = self.validate(rules).into_iter();
+        match findings.find(|found| found.shortfall == Shortfall::Unsupported) {
+            Some(found) => Err(SegmentationError::UnsupportedRule {
+                backend: self.backend,
+                rule: found.rule,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The rules of `rules` that `I` will not read as written: those it passes over, refuses, or
+/// reads as something else.
+///
+/// An empty list is a ruleset the backend reads in full. `Unsupported` findings are the ones
+/// [`try_prepare`](WordBoundResolverImpl::try_prepare) fails on, and the ones that panic anywhere
+/// else.
+pub fn validate<I: WordBoundResolverImpl>(rules: &RuleSet) -> Vec<Finding> {
+    I::capabilities_for(rules).validate(rules)
+}
+
+/// Whether a removal is read during resolution, rather than within each word once it has been
+/// placed, of what leads or trails the input before it is resolved, or not at all.
+pub(crate) fn removes_in_resolution(mode: &RemoveMode) -> bool {
+    !(mode.scope() == Some(&Scope::SingleWord)
+        || matches!(mode, RemoveMode::None | RemoveMode::Prepended(_) | RemoveMode::Appended(_)))
+}
@@ -0,0 +1,298 @@
+//! Identifier case conversion, as segmentation followed by joining.
+//!
+//! Converting `JSONResponse` to `json_response` is two questions: where the words are, and how to
+//! put them back together. The first is what the rest of the crate answers, so this module only
+//! answers the second. Acronyms come out as one word because the rules bound them as one
+//! (`BoundEnd(Acronym)` in the defaults), not because anything here looks for them.
+//!
+//! Separators do not survive conversion. A segment of punctuation, such as the `_` the default
+//! rules keep at the ends of `_private_`, is what the words were separated by in the old case, and
+//! the new case brings its own.
+//!
+//! Where a case capitalises its words, an acronym can be written `Json` or `JSON`, and which one is
+//! a house style rather than something the input decides. [`AcronymStyle`] is that choice, and
+//! [`Converter`] is a case with one made.
+
+use crate::categories;
+use crate::impls::charwalk::Charwalk;
+use crate::resolver::WordBoundResolver;
+use crate::rules::{DefaultRules, ResolverRules};
+use crate::segment::{Segment, SegmentKind};
+use crate::WordBoundResolverImpl;
+
+/// The identifier cases a string can be converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `json_response`
+    Snake,
+    /// `JSON_RESPONSE`
+    ScreamingSnake,
+    /// `json-response`
+    Kebab,
+    /// `jsonResponse`
+    Camel,
+    /// `JsonResponse`
+    Pascal,
+    /// `Json-Response`
+    Train,
+    /// `Json Response`
+    Title,
+    /// `json.response`
+    Dot,
+    /// `json/response`
+    Path,
+}
+
+/// How one word is cased within an identifier.
+#[derive(Clone, Copy)]
+enum WordCase {
+    Lower,
+    Upper,
+    Capitalised,
+}
+
+impl Case {
+    /// The separator between words, and how the first word and every word after it are cased.
+    fn shape(&self) -> (&'static str, WordCase, WordCase) {
+        match self {
+            Case::Snake => ("_", WordCase::Lower, WordCase::Lower),
+            Case::ScreamingSnake => ("_", WordCase::Upper, WordCase::Upper),
+            Case::Kebab => ("-", WordCase::Lower, WordCase::Lower),
+            Case::Camel => ("", WordCase::Lower, WordCase::Capitalised),
+            Case::Pascal => ("", WordCase::Capitalised, WordCase::Capitalised),
+            Case::Train => ("-", WordCase::Capitalised, WordCase::Capitalised),
+            Case::Title => (" ", WordCase::Capitalised, WordCase::Capitalised),
+            Case::Dot => (".", WordCase::Lower, WordCase::Lower),
+            Case::Path => ("/", WordCase::Lower, WordCase::Lower),
+        }
+    }
+
+    /// This case, writing acronyms as `acronyms` says.
+    pub fn with_acronyms(self, acronyms: AcronymStyle) -> Converter {
+        Converter {
+            case: self,
+            acronyms,
+        }
+    }
+
+    /// `s` in this case, segmented by `Charwalk` under the default rules.
+    pub fn convert(&self, s: &str) -> String {
+        Converter::from(*self).convert(s)
+    }
+
+    /// `s` in this case, segmented by the given backend and rules.
+    pub fn convert_with<I: WordBoundResolverImpl<R>, R: ResolverRules>(&self, s: &str) -> String {
+        Converter::from(*self).convert_with::<I, R>(s)
+    }
+
+    /// Words already segmented, joined in this case.
+    pub fn join<W: AsRef<str>>(&self, words: impl IntoIterator<Item = W>) -> String {
+        Converter::from(*self).join(words)
+    }
+
+    /// Segments joined in this case, leaving out the ones that were separators.
+    pub fn join_segments<'a>(&self, segments: impl IntoIterator<Item = Segment<'a>>) -> String {
+        Converter::from(*self).join_segments(segments)
+    }
+}
+
+/// How an acronym is written where the case capitalises its words.
+///
+/// Only capitalised words are affected, so this decides between `JsonResponse` and `JSONResponse`
+/// but leaves `json_response`, `JSON_RESPONSE` and the leading `json` of `jsonResponse` alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum AcronymStyle {
+    /// Like any other word: `JsonResponse`.
+    #[default]
+    Capitalise,
+    /// In upper case, as it was detected: `JSONResponse`, and `URLsFound` with a plural.
+    ///
+    /// An acronym is a segment the `Acronym` target tagged as one, or, for words joined without
+    /// their segments, a word of two or more letters written in upper case.
+    Uppercase,
+    /// As this list writes it, for the words on it, and like any other word otherwise.
+    ///
+    /// Matching ignores case, so `["ID", "HTTP", "URL"]` turns `user_id` into `UserID` and
+    /// `http_url` into `HTTPURL`, and an entry written `iOS` comes out as `iOS`. Whether the word
+    /// was detected as an acronym does not matter: the list is the whole of the decision.
+    Listed(Vec<String>),
+}
+
+/// How a word the case would capitalise is written instead.
+enum Written<'s> {
+    Capitalised,
+    Upper,
+    As(&'s str),
+}
+
+impl AcronymStyle {
+    fn written<'s>(&'s self, word: &str, detected: bool) -> Written<'s> {
+        match self {
+            AcronymStyle::Uppercase if detected => Written::Upper,
+            AcronymStyle::Listed(list) => {
+                let word = word.to_lowercase();
+                match list.iter().find(|entry| entry.to_lowercase() == word) {
+                    Some(entry) => Written::As(entry),
+                    None => Written::Capitalised,
+                }
+            },
+            _ => Written::Capitalised,
+        }
+    }
+}
+
+/// A case, and how it writes acronyms.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Converter {
+    pub case: Case,
+    pub acronyms: AcronymStyle,
+}
+
+impl From<Case> for Converter {
+    fn from(case: Case) -> Self {
+        case.with_acronyms(AcronymStyle::default())
+    }
+}
+
+impl Converter {
+    /// `s` converted, segmented by `Charwalk` under the default rules.
+    pub fn convert(&self, s: &str) -> String {
+        self.convert_with::<Charwalk, DefaultRules>(s)
+    }
+
+    /// `s` converted, segmented by the given backend and rules.
+    pub fn convert_with<I: WordBoundResolverImpl<R>, R: ResolverRules>(&self, s: &str) -> String {
+        self.join_segments(WordBoundResolver::<I, R>::iter(s))
+    }
+
+    /// Words already segmented, joined.
+    ///
+    /// Without segments there is no record of which words were acronyms, so a word of two or more
+    /// letters in upper case is taken to be one.
+    pub fn join<W: AsRef<str>>(&self, words: impl IntoIterator<Item = W>) -> String {
+        self.join_detected(words.into_iter().map(|word| {
+            let detected = looks_like_acronym(word.as_ref());
+            (word, detected)
+        }))
+    }
+
+    /// Segments joined, leaving out the ones that were separators.
+    pub fn join_segments<'a>(&self, segments: impl IntoIterator<Item = Segment<'a>>) -> String {
+        self.join_detected(
+            segments
+                .into_iter()
+                .filter(|segment| {
+                    !matches!(segment.kind, SegmentKind::Punct | SegmentKind::PunctRun)
+                })
+                .map(|segment| (segment.text, segment.kind == SegmentKind::Acronym)),
+        )
+    }
+
+    fn join_detected<W: AsRef<str>>(&self, words: impl IntoIterator<Item = (W, bool)>) -> String {
+        let (separator, first_case, rest_case) = self.case.shape();
+        let mut joined = String::new();
+        for (idx, (word, detected)) in words.into_iter().enumerate() {
+            if idx > 0 {
+                joined.push_str(separator);
+            }
+            let word = word.as_ref();
+            let case = if idx == 0 { first_case } else { rest_case };
+            match case {
+                WordCase::Capitalised => match self.acronyms.written(word, detected) {
+                    Written::Capitalised => push_cased(&mut joined, word, case),
+                    // an acronym's plural `s` is the one letter it has in lower case
+                    Written::Upper => match word.strip_suffix('s') {
+                        Some(acronym) => {
+                            push_cased(&mut joined, acronym, WordCase::Upper);
+                            joined.push('s');
+                        },
+                        None => push_cased(&mut joined, word, WordCase::Upper),
+                    },
+                    Written::As(listed) => joined.push_str(listed),
+                },
+                _ => push_cased(&mut joined, word, case),
+            }
+        }
+        joined
+    }
+}
+
+fn looks_like_acronym(word: &str) -> bool {
+    word.chars().nth(1).is_some() && word.chars().all(|c| c.is_alphabetic() && !c.is_lowercase())
+}
+
+/// `word` cased whole, rather than a character at a time, so that a final sigma comes out `ς`.
+fn push_cased(out: &mut String, word: &str, case: WordCase) {
+    match case {
+        WordCase::Lower => out.push_str(&word.to_lowercase()),
+        WordCase::Upper => out.push_str(&word.to_uppercase()),
+        WordCase::Capitalised => {
+            if let Some(first) = word.chars().next() {
+                match categories::titlecase_of(first) {
+                    Some(title) => out.push(title),
+                    None => out.extend(first.to_uppercase()),
+                }
+                // the first character lowercases the same alone as in the word, so what follows
+                // its lower case is the rest of the word's
+                let lower = word.to_lowercase();
+                let first_len: usize = first.to_lowercase().map(char::len_utf8).sum();
+                out.push_str(&lower[first_len..]);
+            }
+        },
+    }
+}
+
+/// Case conversion on string slices, segmented by `Charwalk` under the default rules.
+///
+/// ```
+/// use word_bounds::case::StrExt;
+///
+/// assert_eq!("JSONResponse".to_snake_case(), "json_response");
+/// assert_eq!("json_response".to_pascal_case(), "JsonResponse");
+/// ```
+pub trait StrExt {
+    /// This string in `case`, which is a [`Case`] or a [`Converter`] with an acronym style.
+    fn to_case(&self, case: impl Into<Converter>) -> String;
+
+    fn to_snake_case(&self) -> String {
+        self.to_case(Case::Snake)
+    }
+
+    fn to_screaming_snake_case(&self) -> String {
+        self.to_case(Case::ScreamingSnake)
+    }
+
+    fn to_kebab_case(&self) -> String {
+        self.to_case(Case::Kebab)
+    }
+
+    fn to_camel_case(&self) -> String {
+        self.to_case(Case::Camel)
+    }
+
+    fn to_pascal_case(&self) -> String {
+        self.to_case(Case::Pascal)
+    }
+
+    fn to_train_case(&self) -> String {
+        self.to_case(Case::Train)
+    }
+
+    fn to_title_case(&self) -> String {
+        self.to_case(Case::Title)
+    }
+
+    fn to_dot_case(&self) -> String {
+        self.to_case(Case::Dot)
+    }
+
+    fn to_path_case(&self) -> String {
+        self.to_case(Case::Path)
+    }
+}
+
+impl StrExt for str {
+    fn to_case(&self, case: impl Into<Converter>) -> String {
+        case.into().convert(self)
+    }
+}
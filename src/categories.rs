@@ -0,0 +1,469 @@
+//! The Unicode general categories of punctuation and symbols, for reading what lies beyond ASCII.
+//!
+//! Within ASCII the rules list their punctuation and their special characters. Beyond it there is
+//! too much to list, so `—`, `…`, `«`, `、` or `€` are read by their category instead, as
+//! [`ResolverRules::punct_categories`] and [`ResolverRules::special_categories`] choose. The tables
+//! are those of Unicode 16.0.0, which the regex engines build their `\p{..}` classes from too, so
+//! that every backend sorts a character the same way. The titlecase letters, which the standard
+//! library has no test for, are here too.
+//!
+//! [`ResolverRules::punct_categories`]: crate::rules::ResolverRules::punct_categories
+//! [`ResolverRules::special_categories`]: crate::rules::ResolverRules::special_categories
+
+use std::cmp::Ordering;
+
+use GeneralCategory::{Pc, Pd, Pe, Pf, Pi, Po, Ps, Sc, Sk, Sm, So};
+
+/// A general category of punctuation or of symbols, by the abbreviation Unicode gives it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeneralCategory {
+    /// Connector punctuation, such as `‿`.
+    Pc,
+    /// Dashes, such as `—` and `–`.
+    Pd,
+    /// Opening brackets and quotes, such as `「` and `„`.
+    Ps,
+    /// Closing brackets, such as `」`.
+    Pe,
+    /// Initial quotes, such as `«` and `“`.
+    Pi,
+    /// Final quotes, such as `»` and `”`.
+    Pf,
+    /// Other punctuation, such as `…`, `、`, `·` and `¿`.
+    Po,
+    /// Mathematical symbols, such as `≠` and `×`.
+    Sm,
+    /// Currency symbols, such as `€`.
+    Sc,
+    /// Modifier symbols, such as `˜`, and the skin tones emoji take.
+    Sk,
+    /// Other symbols, such as `©`, `°` and most emoji.
+    So,
+}
+
+impl GeneralCategory {
+    /// The punctuation categories.
+    pub const PUNCTUATION: [GeneralCategory; 7] = [Pc, Pd, Ps, Pe, Pi, Pf, Po];
+    /// The symbol categories.
+    pub const SYMBOLS: [GeneralCategory; 4] = [Sm, Sc, Sk, So];
+
+    /// The category of `c`, if it is punctuation or a symbol.
+    pub fn of(c: char) -> Option<Self> {
+        find(CATEGORIES, c, |&(start, end, _)| (start, end)).map(|idx| CATEGORIES[idx].2)
+    }
+
+    /// The category as regex syntax names it, as in `\p{Pd}`.
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Pc => "Pc",
+            Pd => "Pd",
+            Ps => "Ps",
+            Pe => "Pe",
+            Pi => "Pi",
+            Pf => "Pf",
+            Po => "Po",
+            Sm => "Sm",
+            Sc => "Sc",
+            Sk => "Sk",
+            So => "So",
+        }
+    }
+
+    /// The category's bit in a set of them.
+    #[inline]
+    pub(crate) fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// Whether `c` is a mark, which combines with the character before it.
+#[cfg(not(feature = "enhanced_accuracy"))]
+pub(crate) fn is_mark(c: char) -> bool {
+    !c.is_ascii() && find(MARKS, c, |&range| range).is_some()
+}
+
+/// Whether `c` is a titlecase letter, such as the `ǅ` a digraph starts a word with.
+///
+/// These are neither upper nor lower case, and the standard library has no test for them.
+pub(crate) fn is_titlecase(c: char) -> bool {
+    !c.is_ascii() && find(TITLECASE, c, |&range| range).is_some()
+}
+
+/// The titlecase letter `c` is a case of, where it is one: `ǅ` for each of `Ǆ`, `ǅ` and `ǆ`, and
+/// `ᾨ` for `ᾠ`, whose upper case is `ὨΙ`.
+pub(crate) fn titlecase_of(c: char) -> Option<char> {
+    if c.is_ascii() {
+        return None;
+    }
+    TITLECASE
+        .iter()
+        .flat_map(|&(start, end)| start..=end)
+        .find(|&title| title == c || title.to_lowercase().eq(c.to_lowercase()))
+}
+
+/// Where the range holding `c` is in `ranges`, which are ascending and apart, if one holds it.
+fn find<T>(ranges: &[T], c: char, range: impl Fn(&T) -> (char, char)) -> Option<usize> {
+    let found = ranges.binary_search_by(|entry| {
+        let (start, end) = range(entry);
+        if end < c {
+            Ordering::Less
+        } else if start > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+    found.ok()
+}
+
+// Generated from the general category tables of Unicode 16.0.0.
+
+/// The punctuation and the symbols, by category: ranges of characters, ascending.
+#[rustfmt::skip]
+const CATEGORIES: &[(char, char, GeneralCategory)] = &[
+    ('\u{21}', '\u{23}', Po), ('\u{24}', '\u{24}', Sc), ('\u{25}', '\u{27}', Po),
+    ('\u{28}', '\u{28}', Ps), ('\u{29}', '\u{29}', Pe), ('\u{2a}', '\u{2a}', Po),
+    ('\u{2b}', '\u{2b}', Sm), ('\u{2c}', '\u{2c}', Po), ('\u{2d}', '\u{2d}', Pd),
+    ('\u{2e}', '\u{2f}', Po), ('\u{3a}', '\u{3b}', Po), ('\u{3c}', '\u{3e}', Sm),
+    ('\u{3f}', '\u{40}', Po), ('\u{5b}', '\u{5b}', Ps), ('\u{5c}', '\u{5c}', Po),
+    ('\u{5d}', '\u{5d}', Pe), ('\u{5e}', '\u{5e}', Sk), ('\u{5f}', '\u{5f}', Pc),
+    ('\u{60}', '\u{60}', Sk), ('\u{7b}', '\u{7b}', Ps), ('\u{7c}', '\u{7c}', Sm),
+    ('\u{7d}', '\u{7d}', Pe), ('\u{7e}', '\u{7e}', Sm), ('\u{a1}', '\u{a1}', Po),
+    ('\u{a2}', '\u{a5}', Sc), ('\u{a6}', '\u{a6}', So), ('\u{a7}', '\u{a7}', Po),
+    ('\u{a8}', '\u{a8}', Sk), ('\u{a9}', '\u{a9}', So), ('\u{ab}', '\u{ab}', Pi),
+    ('\u{ac}', '\u{ac}', Sm), ('\u{ae}', '\u{ae}', So), ('\u{af}', '\u{af}', Sk),
+    ('\u{b0}', '\u{b0}', So), ('\u{b1}', '\u{b1}', Sm), ('\u{b4}', '\u{b4}', Sk),
+    ('\u{b6}', '\u{b7}', Po), ('\u{b8}', '\u{b8}', Sk), ('\u{bb}', '\u{bb}', Pf),
+    ('\u{bf}', '\u{bf}', Po), ('\u{d7}', '\u{d7}', Sm), ('\u{f7}', '\u{f7}', Sm),
+    ('\u{2c2}', '\u{2c5}', Sk), ('\u{2d2}', '\u{2df}', Sk), ('\u{2e5}', '\u{2eb}', Sk),
+    ('\u{2ed}', '\u{2ed}', Sk), ('\u{2ef}', '\u{2ff}', Sk), ('\u{375}', '\u{375}', Sk),
+    ('\u{37e}', '\u{37e}', Po), ('\u{384}', '\u{385}', Sk), ('\u{387}', '\u{387}', Po),
+    ('\u{3f6}', '\u{3f6}', Sm), ('\u{482}', '\u{482}', So), ('\u{55a}', '\u{55f}', Po),
+    ('\u{589}', '\u{589}', Po), ('\u{58a}', '\u{58a}', Pd), ('\u{58d}', '\u{58e}', So),
+    ('\u{58f}', '\u{58f}', Sc), ('\u{5be}', '\u{5be}', Pd), ('\u{5c0}', '\u{5c0}', Po),
+    ('\u{5c3}', '\u{5c3}', Po), ('\u{5c6}', '\u{5c6}', Po), ('\u{5f3}', '\u{5f4}', Po),
+    ('\u{606}', '\u{608}', Sm), ('\u{609}', '\u{60a}', Po), ('\u{60b}', '\u{60b}', Sc),
+    ('\u{60c}', '\u{60d}', Po), ('\u{60e}', '\u{60f}', So), ('\u{61b}', '\u{61b}', Po),
+    ('\u{61d}', '\u{61f}', Po), ('\u{66a}', '\u{66d}', Po), ('\u{6d4}', '\u{6d4}', Po),
+    ('\u{6de}', '\u{6de}', So), ('\u{6e9}', '\u{6e9}', So), ('\u{6fd}', '\u{6fe}', So),
+    ('\u{700}', '\u{70d}', Po), ('\u{7f6}', '\u{7f6}', So), ('\u{7f7}', '\u{7f9}', Po),
+    ('\u{7fe}', '\u{7ff}', Sc), ('\u{830}', '\u{83e}', Po), ('\u{85e}', '\u{85e}', Po),
+    ('\u{888}', '\u{888}', Sk), ('\u{964}', '\u{965}', Po), ('\u{970}', '\u{970}', Po),
+    ('\u{9f2}', '\u{9f3}', Sc), ('\u{9fa}', '\u{9fa}', So), ('\u{9fb}', '\u{9fb}', Sc),
+    ('\u{9fd}', '\u{9fd}', Po), ('\u{a76}', '\u{a76}', Po), ('\u{af0}', '\u{af0}', Po),
+    ('\u{af1}', '\u{af1}', Sc), ('\u{b70}', '\u{b70}', So), ('\u{bf3}', '\u{bf8}', So),
+    ('\u{bf9}', '\u{bf9}', Sc), ('\u{bfa}', '\u{bfa}', So), ('\u{c77}', '\u{c77}', Po),
+    ('\u{c7f}', '\u{c7f}', So), ('\u{c84}', '\u{c84}', Po), ('\u{d4f}', '\u{d4f}', So),
+    ('\u{d79}', '\u{d79}', So), ('\u{df4}', '\u{df4}', Po), ('\u{e3f}', '\u{e3f}', Sc),
+    ('\u{e4f}', '\u{e4f}', Po), ('\u{e5a}', '\u{e5b}', Po), ('\u{f01}', '\u{f03}', So),
+    ('\u{f04}', '\u{f12}', Po), ('\u{f13}', '\u{f13}', So), ('\u{f14}', '\u{f14}', Po),
+    ('\u{f15}', '\u{f17}', So), ('\u{f1a}', '\u{f1f}', So), ('\u{f34}', '\u{f34}', So),
+    ('\u{f36}', '\u{f36}', So), ('\u{f38}', '\u{f38}', So), ('\u{f3a}', '\u{f3a}', Ps),
+    ('\u{f3b}', '\u{f3b}', Pe), ('\u{f3c}', '\u{f3c}', Ps), ('\u{f3d}', '\u{f3d}', Pe),
+    ('\u{f85}', '\u{f85}', Po), ('\u{fbe}', '\u{fc5}', So), ('\u{fc7}', '\u{fcc}', So),
+    ('\u{fce}', '\u{fcf}', So), ('\u{fd0}', '\u{fd4}', Po), ('\u{fd5}', '\u{fd8}', So),
+    ('\u{fd9}', '\u{fda}', Po), ('\u{104a}', '\u{104f}', Po), ('\u{109e}', '\u{109f}', So),
+    ('\u{10fb}', '\u{10fb}', Po), ('\u{1360}', '\u{1368}', Po), ('\u{1390}', '\u{1399}', So),
+    ('\u{1400}', '\u{1400}', Pd), ('\u{166d}', '\u{166d}', So), ('\u{166e}', '\u{166e}', Po),
+    ('\u{169b}', '\u{169b}', Ps), ('\u{169c}', '\u{169c}', Pe), ('\u{16eb}', '\u{16ed}', Po),
+    ('\u{1735}', '\u{1736}', Po), ('\u{17d4}', '\u{17d6}', Po), ('\u{17d8}', '\u{17da}', Po),
+    ('\u{17db}', '\u{17db}', Sc), ('\u{1800}', '\u{1805}', Po), ('\u{1806}', '\u{1806}', Pd),
+    ('\u{1807}', '\u{180a}', Po), ('\u{1940}', '\u{1940}', So), ('\u{1944}', '\u{1945}', Po),
+    ('\u{19de}', '\u{19ff}', So), ('\u{1a1e}', '\u{1a1f}', Po), ('\u{1aa0}', '\u{1aa6}', Po),
+    ('\u{1aa8}', '\u{1aad}', Po), ('\u{1b4e}', '\u{1b4f}', Po), ('\u{1b5a}', '\u{1b60}', Po),
+    ('\u{1b61}', '\u{1b6a}', So), ('\u{1b74}', '\u{1b7c}', So), ('\u{1b7d}', '\u{1b7f}', Po),
+    ('\u{1bfc}', '\u{1bff}', Po), ('\u{1c3b}', '\u{1c3f}', Po), ('\u{1c7e}', '\u{1c7f}', Po),
+    ('\u{1cc0}', '\u{1cc7}', Po), ('\u{1cd3}', '\u{1cd3}', Po), ('\u{1fbd}', '\u{1fbd}', Sk),
+    ('\u{1fbf}', '\u{1fc1}', Sk), ('\u{1fcd}', '\u{1fcf}', Sk), ('\u{1fdd}', '\u{1fdf}', Sk),
+    ('\u{1fed}', '\u{1fef}', Sk), ('\u{1ffd}', '\u{1ffe}', Sk), ('\u{2010}', '\u{2015}', Pd),
+    ('\u{2016}', '\u{2017}', Po), ('\u{2018}', '\u{2018}', Pi), ('\u{2019}', '\u{2019}', Pf),
+    ('\u{201a}', '\u{201a}', Ps), ('\u{201b}', '\u{201c}', Pi), ('\u{201d}', '\u{201d}', Pf),
+    ('\u{201e}', '\u{201e}', Ps), ('\u{201f}', '\u{201f}', Pi), ('\u{2020}', '\u{2027}', Po),
+    ('\u{2030}', '\u{2038}', Po), ('\u{2039}', '\u{2039}', Pi), ('\u{203a}', '\u{203a}', Pf),
+    ('\u{203b}', '\u{203e}', Po), ('\u{203f}', '\u{2040}', Pc), ('\u{2041}', '\u{2043}', Po),
+    ('\u{2044}', '\u{2044}', Sm), ('\u{2045}', '\u{2045}', Ps), ('\u{2046}', '\u{2046}', Pe),
+    ('\u{2047}', '\u{2051}', Po), ('\u{2052}', '\u{2052}', Sm), ('\u{2053}', '\u{2053}', Po),
+    ('\u{2054}', '\u{2054}', Pc), ('\u{2055}', '\u{205e}', Po), ('\u{207a}', '\u{207c}', Sm),
+    ('\u{207d}', '\u{207d}', Ps), ('\u{207e}', '\u{207e}', Pe), ('\u{208a}', '\u{208c}', Sm),
+    ('\u{208d}', '\u{208d}', Ps), ('\u{208e}', '\u{208e}', Pe), ('\u{20a0}', '\u{20c0}', Sc),
+    ('\u{2100}', '\u{2101}', So), ('\u{2103}', '\u{2106}', So), ('\u{2108}', '\u{2109}', So),
+    ('\u{2114}', '\u{2114}', So), ('\u{2116}', '\u{2117}', So), ('\u{2118}', '\u{2118}', Sm),
+    ('\u{211e}', '\u{2123}', So), ('\u{2125}', '\u{2125}', So), ('\u{2127}', '\u{2127}', So),
+    ('\u{2129}', '\u{2129}', So), ('\u{212e}', '\u{212e}', So), ('\u{213a}', '\u{213b}', So),
+    ('\u{2140}', '\u{2144}', Sm), ('\u{214a}', '\u{214a}', So), ('\u{214b}', '\u{214b}', Sm),
+    ('\u{214c}', '\u{214d}', So), ('\u{214f}', '\u{214f}', So), ('\u{218a}', '\u{218b}', So),
+    ('\u{2190}', '\u{2194}', Sm), ('\u{2195}', '\u{2199}', So), ('\u{219a}', '\u{219b}', Sm),
+    ('\u{219c}', '\u{219f}', So), ('\u{21a0}', '\u{21a0}', Sm), ('\u{21a1}', '\u{21a2}', So),
+    ('\u{21a3}', '\u{21a3}', Sm), ('\u{21a4}', '\u{21a5}', So), ('\u{21a6}', '\u{21a6}', Sm),
+    ('\u{21a7}', '\u{21ad}', So), ('\u{21ae}', '\u{21ae}', Sm), ('\u{21af}', '\u{21cd}', So),
+    ('\u{21ce}', '\u{21cf}', Sm), ('\u{21d0}', '\u{21d1}', So), ('\u{21d2}', '\u{21d2}', Sm),
+    ('\u{21d3}', '\u{21d3}', So), ('\u{21d4}', '\u{21d4}', Sm), ('\u{21d5}', '\u{21f3}', So),
+    ('\u{21f4}', '\u{22ff}', Sm), ('\u{2300}', '\u{2307}', So), ('\u{2308}', '\u{2308}', Ps),
+    ('\u{2309}', '\u{2309}', Pe), ('\u{230a}', '\u{230a}', Ps), ('\u{230b}', '\u{230b}', Pe),
+    ('\u{230c}', '\u{231f}', So), ('\u{2320}', '\u{2321}', Sm), ('\u{2322}', '\u{2328}', So),
+    ('\u{2329}', '\u{2329}', Ps), ('\u{232a}', '\u{232a}', Pe), ('\u{232b}', '\u{237b}', So),
+    ('\u{237c}', '\u{237c}', Sm), ('\u{237d}', '\u{239a}', So), ('\u{239b}', '\u{23b3}', Sm),
+    ('\u{23b4}', '\u{23db}', So), ('\u{23dc}', '\u{23e1}', Sm), ('\u{23e2}', '\u{2429}', So),
+    ('\u{2440}', '\u{244a}', So), ('\u{249c}', '\u{24e9}', So), ('\u{2500}', '\u{25b6}', So),
+    ('\u{25b7}', '\u{25b7}', Sm), ('\u{25b8}', '\u{25c0}', So), ('\u{25c1}', '\u{25c1}', Sm),
+    ('\u{25c2}', '\u{25f7}', So), ('\u{25f8}', '\u{25ff}', Sm), ('\u{2600}', '\u{266e}', So),
+    ('\u{266f}', '\u{266f}', Sm), ('\u{2670}', '\u{2767}', So), ('\u{2768}', '\u{2768}', Ps),
+    ('\u{2769}', '\u{2769}', Pe), ('\u{276a}', '\u{276a}', Ps), ('\u{276b}', '\u{276b}', Pe),
+    ('\u{276c}', '\u{276c}', Ps), ('\u{276d}', '\u{276d}', Pe), ('\u{276e}', '\u{276e}', Ps),
+    ('\u{276f}', '\u{276f}', Pe), ('\u{2770}', '\u{2770}', Ps), ('\u{2771}', '\u{2771}', Pe),
+    ('\u{2772}', '\u{2772}', Ps), ('\u{2773}', '\u{2773}', Pe), ('\u{2774}', '\u{2774}', Ps),
+    ('\u{2775}', '\u{2775}', Pe), ('\u{2794}', '\u{27bf}', So), ('\u{27c0}', '\u{27c4}', Sm),
+    ('\u{27c5}', '\u{27c5}', Ps), ('\u{27c6}', '\u{27c6}', Pe), ('\u{27c7}', '\u{27e5}', Sm),
+    ('\u{27e6}', '\u{27e6}', Ps), ('\u{27e7}', '\u{27e7}', Pe), ('\u{27e8}', '\u{27e8}', Ps),
+    ('\u{27e9}', '\u{27e9}', Pe), ('\u{27ea}', '\u{27ea}', Ps), ('\u{27eb}', '\u{27eb}', Pe),
+    ('\u{27ec}', '\u{27ec}', Ps), ('\u{27ed}', '\u{27ed}', Pe), ('\u{27ee}', '\u{27ee}', Ps),
+    ('\u{27ef}', '\u{27ef}', Pe), ('\u{27f0}', '\u{27ff}', Sm), ('\u{2800}', '\u{28ff}', So),
+    ('\u{2900}', '\u{2982}', Sm), ('\u{2983}', '\u{2983}', Ps), ('\u{2984}', '\u{2984}', Pe),
+    ('\u{2985}', '\u{2985}', Ps), ('\u{2986}', '\u{2986}', Pe), ('\u{2987}', '\u{2987}', Ps),
+    ('\u{2988}', '\u{2988}', Pe), ('\u{2989}', '\u{2989}', Ps), ('\u{298a}', '\u{298a}', Pe),
+    ('\u{298b}', '\u{298b}', Ps), ('\u{298c}', '\u{298c}', Pe), ('\u{298d}', '\u{298d}', Ps),
+    ('\u{298e}', '\u{298e}', Pe), ('\u{298f}', '\u{298f}', Ps), ('\u{2990}', '\u{2990}', Pe),
+    ('\u{2991}', '\u{2991}', Ps), ('\u{2992}', '\u{2992}', Pe), ('\u{2993}', '\u{2993}', Ps),
+    ('\u{2994}', '\u{2994}', Pe), ('\u{2995}', '\u{2995}', Ps), ('\u{2996}', '\u{2996}', Pe),
+    ('\u{2997}', '\u{2997}', Ps), ('\u{2998}', '\u{2998}', Pe), ('\u{2999}', '\u{29d7}', Sm),
+    ('\u{29d8}', '\u{29d8}', Ps), ('\u{29d9}', '\u{29d9}', Pe), ('\u{29da}', '\u{29da}', Ps),
+    ('\u{29db}', '\u{29db}', Pe), ('\u{29dc}', '\u{29fb}', Sm), ('\u{29fc}', '\u{29fc}', Ps),
+    ('\u{29fd}', '\u{29fd}', Pe), ('\u{29fe}', '\u{2aff}', Sm), ('\u{2b00}', '\u{2b2f}', So),
+    ('\u{2b30}', '\u{2b44}', Sm), ('\u{2b45}', '\u{2b46}', So), ('\u{2b47}', '\u{2b4c}', Sm),
+    ('\u{2b4d}', '\u{2b73}', So), ('\u{2b76}', '\u{2b95}', So), ('\u{2b97}', '\u{2bff}', So),
+    ('\u{2ce5}', '\u{2cea}', So), ('\u{2cf9}', '\u{2cfc}', Po), ('\u{2cfe}', '\u{2cff}', Po),
+    ('\u{2d70}', '\u{2d70}', Po), ('\u{2e00}', '\u{2e01}', Po), ('\u{2e02}', '\u{2e02}', Pi),
+    ('\u{2e03}', '\u{2e03}', Pf), ('\u{2e04}', '\u{2e04}', Pi), ('\u{2e05}', '\u{2e05}', Pf),
+    ('\u{2e06}', '\u{2e08}', Po), ('\u{2e09}', '\u{2e09}', Pi), ('\u{2e0a}', '\u{2e0a}', Pf),
+    ('\u{2e0b}', '\u{2e0b}', Po), ('\u{2e0c}', '\u{2e0c}', Pi), ('\u{2e0d}', '\u{2e0d}', Pf),
+    ('\u{2e0e}', '\u{2e16}', Po), ('\u{2e17}', '\u{2e17}', Pd), ('\u{2e18}', '\u{2e19}', Po),
+    ('\u{2e1a}', '\u{2e1a}', Pd), ('\u{2e1b}', '\u{2e1b}', Po), ('\u{2e1c}', '\u{2e1c}', Pi),
+    ('\u{2e1d}', '\u{2e1d}', Pf), ('\u{2e1e}', '\u{2e1f}', Po), ('\u{2e20}', '\u{2e20}', Pi),
+    ('\u{2e21}', '\u{2e21}', Pf), ('\u{2e22}', '\u{2e22}', Ps), ('\u{2e23}', '\u{2e23}', Pe),
+    ('\u{2e24}', '\u{2e24}', Ps), ('\u{2e25}', '\u{2e25}', Pe), ('\u{2e26}', '\u{2e26}', Ps),
+    ('\u{2e27}', '\u{2e27}', Pe), ('\u{2e28}', '\u{2e28}', Ps), ('\u{2e29}', '\u{2e29}', Pe),
+    ('\u{2e2a}', '\u{2e2e}', Po), ('\u{2e30}', '\u{2e39}', Po), ('\u{2e3a}', '\u{2e3b}', Pd),
+    ('\u{2e3c}', '\u{2e3f}', Po), ('\u{2e40}', '\u{2e40}', Pd), ('\u{2e41}', '\u{2e41}', Po),
+    ('\u{2e42}', '\u{2e42}', Ps), ('\u{2e43}', '\u{2e4f}', Po), ('\u{2e50}', '\u{2e51}', So),
+    ('\u{2e52}', '\u{2e54}', Po), ('\u{2e55}', '\u{2e55}', Ps), ('\u{2e56}', '\u{2e56}', Pe),
+    ('\u{2e57}', '\u{2e57}', Ps), ('\u{2e58}', '\u{2e58}', Pe), ('\u{2e59}', '\u{2e59}', Ps),
+    ('\u{2e5a}', '\u{2e5a}', Pe), ('\u{2e5b}', '\u{2e5b}', Ps), ('\u{2e5c}', '\u{2e5c}', Pe),
+    ('\u{2e5d}', '\u{2e5d}', Pd), ('\u{2e80}', '\u{2e99}', So), ('\u{2e9b}', '\u{2ef3}', So),
+    ('\u{2f00}', '\u{2fd5}', So), ('\u{2ff0}', '\u{2fff}', So), ('\u{3001}', '\u{3003}', Po),
+    ('\u{3004}', '\u{3004}', So), ('\u{3008}', '\u{3008}', Ps), ('\u{3009}', '\u{3009}', Pe),
+    ('\u{300a}', '\u{300a}', Ps), ('\u{300b}', '\u{300b}', Pe), ('\u{300c}', '\u{300c}', Ps),
+    ('\u{300d}', '\u{300d}', Pe), ('\u{300e}', '\u{300e}', Ps), ('\u{300f}', '\u{300f}', Pe),
+    ('\u{3010}', '\u{3010}', Ps), ('\u{3011}', '\u{3011}', Pe), ('\u{3012}', '\u{3013}', So),
+    ('\u{3014}', '\u{3014}', Ps), ('\u{3015}', '\u{3015}', Pe), ('\u{3016}', '\u{3016}', Ps),
+    ('\u{3017}', '\u{3017}', Pe), ('\u{3018}', '\u{3018}', Ps), ('\u{3019}', '\u{3019}', Pe),
+    ('\u{301a}', '\u{301a}', Ps), ('\u{301b}', '\u{301b}', Pe), ('\u{301c}', '\u{301c}', Pd),
+    ('\u{301d}', '\u{301d}', Ps), ('\u{301e}', '\u{301f}', Pe), ('\u{3020}', '\u{3020}', So),
+    ('\u{3030}', '\u{3030}', Pd), ('\u{3036}', '\u{3037}', So), ('\u{303d}', '\u{303d}', Po),
+    ('\u{303e}', '\u{303f}', So), ('\u{309b}', '\u{309c}', Sk), ('\u{30a0}', '\u{30a0}', Pd),
+    ('\u{30fb}', '\u{30fb}', Po), ('\u{3190}', '\u{3191}', So), ('\u{3196}', '\u{319f}', So),
+    ('\u{31c0}', '\u{31e5}', So), ('\u{31ef}', '\u{31ef}', So), ('\u{3200}', '\u{321e}', So),
+    ('\u{322a}', '\u{3247}', So), ('\u{3250}', '\u{3250}', So), ('\u{3260}', '\u{327f}', So),
+    ('\u{328a}', '\u{32b0}', So), ('\u{32c0}', '\u{33ff}', So), ('\u{4dc0}', '\u{4dff}', So),
+    ('\u{a490}', '\u{a4c6}', So), ('\u{a4fe}', '\u{a4ff}', Po), ('\u{a60d}', '\u{a60f}', Po),
+    ('\u{a673}', '\u{a673}', Po), ('\u{a67e}', '\u{a67e}', Po), ('\u{a6f2}', '\u{a6f7}', Po),
+    ('\u{a700}', '\u{a716}', Sk), ('\u{a720}', '\u{a721}', Sk), ('\u{a789}', '\u{a78a}', Sk),
+    ('\u{a828}', '\u{a82b}', So), ('\u{a836}', '\u{a837}', So), ('\u{a838}', '\u{a838}', Sc),
+    ('\u{a839}', '\u{a839}', So), ('\u{a874}', '\u{a877}', Po), ('\u{a8ce}', '\u{a8cf}', Po),
+    ('\u{a8f8}', '\u{a8fa}', Po), ('\u{a8fc}', '\u{a8fc}', Po), ('\u{a92e}', '\u{a92f}', Po),
+    ('\u{a95f}', '\u{a95f}', Po), ('\u{a9c1}', '\u{a9cd}', Po), ('\u{a9de}', '\u{a9df}', Po),
+    ('\u{aa5c}', '\u{aa5f}', Po), ('\u{aa77}', '\u{aa79}', So), ('\u{aade}', '\u{aadf}', Po),
+    ('\u{aaf0}', '\u{aaf1}', Po), ('\u{ab5b}', '\u{ab5b}', Sk), ('\u{ab6a}', '\u{ab6b}', Sk),
+    ('\u{abeb}', '\u{abeb}', Po), ('\u{fb29}', '\u{fb29}', Sm), ('\u{fbb2}', '\u{fbc2}', Sk),
+    ('\u{fd3e}', '\u{fd3e}', Pe), ('\u{fd3f}', '\u{fd3f}', Ps), ('\u{fd40}', '\u{fd4f}', So),
+    ('\u{fdcf}', '\u{fdcf}', So), ('\u{fdfc}', '\u{fdfc}', Sc), ('\u{fdfd}', '\u{fdff}', So),
+    ('\u{fe10}', '\u{fe16}', Po), ('\u{fe17}', '\u{fe17}', Ps), ('\u{fe18}', '\u{fe18}', Pe),
+    ('\u{fe19}', '\u{fe19}', Po), ('\u{fe30}', '\u{fe30}', Po), ('\u{fe31}', '\u{fe32}', Pd),
+    ('\u{fe33}', '\u{fe34}', Pc), ('\u{fe35}', '\u{fe35}', Ps), ('\u{fe36}', '\u{fe36}', Pe),
+    ('\u{fe37}', '\u{fe37}', Ps), ('\u{fe38}', '\u{fe38}', Pe), ('\u{fe39}', '\u{fe39}', Ps),
+    ('\u{fe3a}', '\u{fe3a}', Pe), ('\u{fe3b}', '\u{fe3b}', Ps), ('\u{fe3c}', '\u{fe3c}', Pe),
+    ('\u{fe3d}', '\u{fe3d}', Ps), ('\u{fe3e}', '\u{fe3e}', Pe), ('\u{fe3f}', '\u{fe3f}', Ps),
+    ('\u{fe40}', '\u{fe40}', Pe), ('\u{fe41}', '\u{fe41}', Ps), ('\u{fe42}', '\u{fe42}', Pe),
+    ('\u{fe43}', '\u{fe43}', Ps), ('\u{fe44}', '\u{fe44}', Pe), ('\u{fe45}', '\u{fe46}', Po),
+    ('\u{fe47}', '\u{fe47}', Ps), ('\u{fe48}', '\u{fe48}', Pe), ('\u{fe49}', '\u{fe4c}', Po),
+    ('\u{fe4d}', '\u{fe4f}', Pc), ('\u{fe50}', '\u{fe52}', Po), ('\u{fe54}', '\u{fe57}', Po),
+    ('\u{fe58}', '\u{fe58}', Pd), ('\u{fe59}', '\u{fe59}', Ps), ('\u{fe5a}', '\u{fe5a}', Pe),
+    ('\u{fe5b}', '\u{fe5b}', Ps), ('\u{fe5c}', '\u{fe5c}', Pe), ('\u{fe5d}', '\u{fe5d}', Ps),
+    ('\u{fe5e}', '\u{fe5e}', Pe), ('\u{fe5f}', '\u{fe61}', Po), ('\u{fe62}', '\u{fe62}', Sm),
+    ('\u{fe63}', '\u{fe63}', Pd), ('\u{fe64}', '\u{fe66}', Sm), ('\u{fe68}', '\u{fe68}', Po),
+    ('\u{fe69}', '\u{fe69}', Sc), ('\u{fe6a}', '\u{fe6b}', Po), ('\u{ff01}', '\u{ff03}', Po),
+    ('\u{ff04}', '\u{ff04}', Sc), ('\u{ff05}', '\u{ff07}', Po), ('\u{ff08}', '\u{ff08}', Ps),
+    ('\u{ff09}', '\u{ff09}', Pe), ('\u{ff0a}', '\u{ff0a}', Po), ('\u{ff0b}', '\u{ff0b}', Sm),
+    ('\u{ff0c}', '\u{ff0c}', Po), ('\u{ff0d}', '\u{ff0d}', Pd), ('\u{ff0e}', '\u{ff0f}', Po),
+    ('\u{ff1a}', '\u{ff1b}', Po), ('\u{ff1c}', '\u{ff1e}', Sm), ('\u{ff1f}', '\u{ff20}', Po),
+    ('\u{ff3b}', '\u{ff3b}', Ps), ('\u{ff3c}', '\u{ff3c}', Po), ('\u{ff3d}', '\u{ff3d}', Pe),
+    ('\u{ff3e}', '\u{ff3e}', Sk), ('\u{ff3f}', '\u{ff3f}', Pc), ('\u{ff40}', '\u{ff40}', Sk),
+    ('\u{ff5b}', '\u{ff5b}', Ps), ('\u{ff5c}', '\u{ff5c}', Sm), ('\u{ff5d}', '\u{ff5d}', Pe),
+    ('\u{ff5e}', '\u{ff5e}', Sm), ('\u{ff5f}', '\u{ff5f}', Ps), ('\u{ff60}', '\u{ff60}', Pe),
+    ('\u{ff61}', '\u{ff61}', Po), ('\u{ff62}', '\u{ff62}', Ps), ('\u{ff63}', '\u{ff63}', Pe),
+    ('\u{ff64}', '\u{ff65}', Po), ('\u{ffe0}', '\u{ffe1}', Sc), ('\u{ffe2}', '\u{ffe2}', Sm),
+    ('\u{ffe3}', '\u{ffe3}', Sk), ('\u{ffe4}', '\u{ffe4}', So), ('\u{ffe5}', '\u{ffe6}', Sc),
+    ('\u{ffe8}', '\u{ffe8}', So), ('\u{ffe9}', '\u{ffec}', Sm), ('\u{ffed}', '\u{ffee}', So),
+    ('\u{fffc}', '\u{fffd}', So), ('\u{10100}', '\u{10102}', Po), ('\u{10137}', '\u{1013f}', So),
+    ('\u{10179}', '\u{10189}', So), ('\u{1018c}', '\u{1018e}', So), ('\u{10190}', '\u{1019c}', So),
+    ('\u{101a0}', '\u{101a0}', So), ('\u{101d0}', '\u{101fc}', So), ('\u{1039f}', '\u{1039f}', Po),
+    ('\u{103d0}', '\u{103d0}', Po), ('\u{1056f}', '\u{1056f}', Po), ('\u{10857}', '\u{10857}', Po),
+    ('\u{10877}', '\u{10878}', So), ('\u{1091f}', '\u{1091f}', Po), ('\u{1093f}', '\u{1093f}', Po),
+    ('\u{10a50}', '\u{10a58}', Po), ('\u{10a7f}', '\u{10a7f}', Po), ('\u{10ac8}', '\u{10ac8}', So),
+    ('\u{10af0}', '\u{10af6}', Po), ('\u{10b39}', '\u{10b3f}', Po), ('\u{10b99}', '\u{10b9c}', Po),
+    ('\u{10d6e}', '\u{10d6e}', Pd), ('\u{10d8e}', '\u{10d8f}', Sm), ('\u{10ead}', '\u{10ead}', Pd),
+    ('\u{10f55}', '\u{10f59}', Po), ('\u{10f86}', '\u{10f89}', Po), ('\u{11047}', '\u{1104d}', Po),
+    ('\u{110bb}', '\u{110bc}', Po), ('\u{110be}', '\u{110c1}', Po), ('\u{11140}', '\u{11143}', Po),
+    ('\u{11174}', '\u{11175}', Po), ('\u{111c5}', '\u{111c8}', Po), ('\u{111cd}', '\u{111cd}', Po),
+    ('\u{111db}', '\u{111db}', Po), ('\u{111dd}', '\u{111df}', Po), ('\u{11238}', '\u{1123d}', Po),
+    ('\u{112a9}', '\u{112a9}', Po), ('\u{113d4}', '\u{113d5}', Po), ('\u{113d7}', '\u{113d8}', Po),
+    ('\u{1144b}', '\u{1144f}', Po), ('\u{1145a}', '\u{1145b}', Po), ('\u{1145d}', '\u{1145d}', Po),
+    ('\u{114c6}', '\u{114c6}', Po), ('\u{115c1}', '\u{115d7}', Po), ('\u{11641}', '\u{11643}', Po),
+    ('\u{11660}', '\u{1166c}', Po), ('\u{116b9}', '\u{116b9}', Po), ('\u{1173c}', '\u{1173e}', Po),
+    ('\u{1173f}', '\u{1173f}', So), ('\u{1183b}', '\u{1183b}', Po), ('\u{11944}', '\u{11946}', Po),
+    ('\u{119e2}', '\u{119e2}', Po), ('\u{11a3f}', '\u{11a46}', Po), ('\u{11a9a}', '\u{11a9c}', Po),
+    ('\u{11a9e}', '\u{11aa2}', Po), ('\u{11b00}', '\u{11b09}', Po), ('\u{11be1}', '\u{11be1}', Po),
+    ('\u{11c41}', '\u{11c45}', Po), ('\u{11c70}', '\u{11c71}', Po), ('\u{11ef7}', '\u{11ef8}', Po),
+    ('\u{11f43}', '\u{11f4f}', Po), ('\u{11fd5}', '\u{11fdc}', So), ('\u{11fdd}', '\u{11fe0}', Sc),
+    ('\u{11fe1}', '\u{11ff1}', So), ('\u{11fff}', '\u{11fff}', Po), ('\u{12470}', '\u{12474}', Po),
+    ('\u{12ff1}', '\u{12ff2}', Po), ('\u{16a6e}', '\u{16a6f}', Po), ('\u{16af5}', '\u{16af5}', Po),
+    ('\u{16b37}', '\u{16b3b}', Po), ('\u{16b3c}', '\u{16b3f}', So), ('\u{16b44}', '\u{16b44}', Po),
+    ('\u{16b45}', '\u{16b45}', So), ('\u{16d6d}', '\u{16d6f}', Po), ('\u{16e97}', '\u{16e9a}', Po),
+    ('\u{16fe2}', '\u{16fe2}', Po), ('\u{1bc9c}', '\u{1bc9c}', So), ('\u{1bc9f}', '\u{1bc9f}', Po),
+    ('\u{1cc00}', '\u{1ccef}', So), ('\u{1cd00}', '\u{1ceb3}', So), ('\u{1cf50}', '\u{1cfc3}', So),
+    ('\u{1d000}', '\u{1d0f5}', So), ('\u{1d100}', '\u{1d126}', So), ('\u{1d129}', '\u{1d164}', So),
+    ('\u{1d16a}', '\u{1d16c}', So), ('\u{1d183}', '\u{1d184}', So), ('\u{1d18c}', '\u{1d1a9}', So),
+    ('\u{1d1ae}', '\u{1d1ea}', So), ('\u{1d200}', '\u{1d241}', So), ('\u{1d245}', '\u{1d245}', So),
+    ('\u{1d300}', '\u{1d356}', So), ('\u{1d6c1}', '\u{1d6c1}', Sm), ('\u{1d6db}', '\u{1d6db}', Sm),
+    ('\u{1d6fb}', '\u{1d6fb}', Sm), ('\u{1d715}', '\u{1d715}', Sm), ('\u{1d735}', '\u{1d735}', Sm),
+    ('\u{1d74f}', '\u{1d74f}', Sm), ('\u{1d76f}', '\u{1d76f}', Sm), ('\u{1d789}', '\u{1d789}', Sm),
+    ('\u{1d7a9}', '\u{1d7a9}', Sm), ('\u{1d7c3}', '\u{1d7c3}', Sm), ('\u{1d800}', '\u{1d9ff}', So),
+    ('\u{1da37}', '\u{1da3a}', So), ('\u{1da6d}', '\u{1da74}', So), ('\u{1da76}', '\u{1da83}', So),
+    ('\u{1da85}', '\u{1da86}', So), ('\u{1da87}', '\u{1da8b}', Po), ('\u{1e14f}', '\u{1e14f}', So),
+    ('\u{1e2ff}', '\u{1e2ff}', Sc), ('\u{1e5ff}', '\u{1e5ff}', Po), ('\u{1e95e}', '\u{1e95f}', Po),
+    ('\u{1ecac}', '\u{1ecac}', So), ('\u{1ecb0}', '\u{1ecb0}', Sc), ('\u{1ed2e}', '\u{1ed2e}', So),
+    ('\u{1eef0}', '\u{1eef1}', Sm), ('\u{1f000}', '\u{1f02b}', So), ('\u{1f030}', '\u{1f093}', So),
+    ('\u{1f0a0}', '\u{1f0ae}', So), ('\u{1f0b1}', '\u{1f0bf}', So), ('\u{1f0c1}', '\u{1f0cf}', So),
+    ('\u{1f0d1}', '\u{1f0f5}', So), ('\u{1f10d}', '\u{1f1ad}', So), ('\u{1f1e6}', '\u{1f202}', So),
+    ('\u{1f210}', '\u{1f23b}', So), ('\u{1f240}', '\u{1f248}', So), ('\u{1f250}', '\u{1f251}', So),
+    ('\u{1f260}', '\u{1f265}', So), ('\u{1f300}', '\u{1f3fa}', So), ('\u{1f3fb}', '\u{1f3ff}', Sk),
+    ('\u{1f400}', '\u{1f6d7}', So), ('\u{1f6dc}', '\u{1f6ec}', So), ('\u{1f6f0}', '\u{1f6fc}', So),
+    ('\u{1f700}', '\u{1f776}', So), ('\u{1f77b}', '\u{1f7d9}', So), ('\u{1f7e0}', '\u{1f7eb}', So),
+    ('\u{1f7f0}', '\u{1f7f0}', So), ('\u{1f800}', '\u{1f80b}', So), ('\u{1f810}', '\u{1f847}', So),
+    ('\u{1f850}', '\u{1f859}', So), ('\u{1f860}', '\u{1f887}', So), ('\u{1f890}', '\u{1f8ad}', So),
+    ('\u{1f8b0}', '\u{1f8bb}', So), ('\u{1f8c0}', '\u{1f8c1}', So), ('\u{1f900}', '\u{1fa53}', So),
+    ('\u{1fa60}', '\u{1fa6d}', So), ('\u{1fa70}', '\u{1fa7c}', So), ('\u{1fa80}', '\u{1fa89}', So),
+    ('\u{1fa8f}', '\u{1fac6}', So), ('\u{1face}', '\u{1fadc}', So), ('\u{1fadf}', '\u{1fae9}', So),
+    ('\u{1faf0}', '\u{1faf8}', So), ('\u{1fb00}', '\u{1fb92}', So), ('\u{1fb94}', '\u{1fbef}', So),
+];
+
+/// The titlecase letters: ranges of characters, ascending.
+#[rustfmt::skip]
+const TITLECASE: &[(char, char)] = &[
+    ('\u{1c5}', '\u{1c5}'), ('\u{1c8}', '\u{1c8}'), ('\u{1cb}', '\u{1cb}'), ('\u{1f2}', '\u{1f2}'),
+    ('\u{1f88}', '\u{1f8f}'), ('\u{1f98}', '\u{1f9f}'), ('\u{1fa8}', '\u{1faf}'),
+    ('\u{1fbc}', '\u{1fbc}'), ('\u{1fcc}', '\u{1fcc}'), ('\u{1ffc}', '\u{1ffc}'),
+];
+
+/// The marks, of every category: ranges of characters, ascending.
+#[cfg(not(feature = "enhanced_accuracy"))]
+#[rustfmt::skip]
+const MARKS: &[(char, char)] = &[
+    ('\u{300}', '\u{36f}'), ('\u{483}', '\u{489}'), ('\u{591}', '\u{5bd}'), ('\u{5bf}', '\u{5bf}'),
+    ('\u{5c1}', '\u{5c2}'), ('\u{5c4}', '\u{5c5}'), ('\u{5c7}', '\u{5c7}'), ('\u{610}', '\u{61a}'),
+    ('\u{64b}', '\u{65f}'), ('\u{670}', '\u{670}'), ('\u{6d6}', '\u{6dc}'), ('\u{6df}', '\u{6e4}'),
+    ('\u{6e7}', '\u{6e8}'), ('\u{6ea}', '\u{6ed}'), ('\u{711}', '\u{711}'), ('\u{730}', '\u{74a}'),
+    ('\u{7a6}', '\u{7b0}'), ('\u{7eb}', '\u{7f3}'), ('\u{7fd}', '\u{7fd}'), ('\u{816}', '\u{819}'),
+    ('\u{81b}', '\u{823}'), ('\u{825}', '\u{827}'), ('\u{829}', '\u{82d}'), ('\u{859}', '\u{85b}'),
+    ('\u{897}', '\u{89f}'), ('\u{8ca}', '\u{8e1}'), ('\u{8e3}', '\u{903}'), ('\u{93a}', '\u{93c}'),
+    ('\u{93e}', '\u{94f}'), ('\u{951}', '\u{957}'), ('\u{962}', '\u{963}'), ('\u{981}', '\u{983}'),
+    ('\u{9bc}', '\u{9bc}'), ('\u{9be}', '\u{9c4}'), ('\u{9c7}', '\u{9c8}'), ('\u{9cb}', '\u{9cd}'),
+    ('\u{9d7}', '\u{9d7}'), ('\u{9e2}', '\u{9e3}'), ('\u{9fe}', '\u{9fe}'), ('\u{a01}', '\u{a03}'),
+    ('\u{a3c}', '\u{a3c}'), ('\u{a3e}', '\u{a42}'), ('\u{a47}', '\u{a48}'), ('\u{a4b}', '\u{a4d}'),
+    ('\u{a51}', '\u{a51}'), ('\u{a70}', '\u{a71}'), ('\u{a75}', '\u{a75}'), ('\u{a81}', '\u{a83}'),
+    ('\u{abc}', '\u{abc}'), ('\u{abe}', '\u{ac5}'), ('\u{ac7}', '\u{ac9}'), ('\u{acb}', '\u{acd}'),
+    ('\u{ae2}', '\u{ae3}'), ('\u{afa}', '\u{aff}'), ('\u{b01}', '\u{b03}'), ('\u{b3c}', '\u{b3c}'),
+    ('\u{b3e}', '\u{b44}'), ('\u{b47}', '\u{b48}'), ('\u{b4b}', '\u{b4d}'), ('\u{b55}', '\u{b57}'),
+    ('\u{b62}', '\u{b63}'), ('\u{b82}', '\u{b82}'), ('\u{bbe}', '\u{bc2}'), ('\u{bc6}', '\u{bc8}'),
+    ('\u{bca}', '\u{bcd}'), ('\u{bd7}', '\u{bd7}'), ('\u{c00}', '\u{c04}'), ('\u{c3c}', '\u{c3c}'),
+    ('\u{c3e}', '\u{c44}'), ('\u{c46}', '\u{c48}'), ('\u{c4a}', '\u{c4d}'), ('\u{c55}', '\u{c56}'),
+    ('\u{c62}', '\u{c63}'), ('\u{c81}', '\u{c83}'), ('\u{cbc}', '\u{cbc}'), ('\u{cbe}', '\u{cc4}'),
+    ('\u{cc6}', '\u{cc8}'), ('\u{cca}', '\u{ccd}'), ('\u{cd5}', '\u{cd6}'), ('\u{ce2}', '\u{ce3}'),
+    ('\u{cf3}', '\u{cf3}'), ('\u{d00}', '\u{d03}'), ('\u{d3b}', '\u{d3c}'), ('\u{d3e}', '\u{d44}'),
+    ('\u{d46}', '\u{d48}'), ('\u{d4a}', '\u{d4d}'), ('\u{d57}', '\u{d57}'), ('\u{d62}', '\u{d63}'),
+    ('\u{d81}', '\u{d83}'), ('\u{dca}', '\u{dca}'), ('\u{dcf}', '\u{dd4}'), ('\u{dd6}', '\u{dd6}'),
+    ('\u{dd8}', '\u{ddf}'), ('\u{df2}', '\u{df3}'), ('\u{e31}', '\u{e31}'), ('\u{e34}', '\u{e3a}'),
+    ('\u{e47}', '\u{e4e}'), ('\u{eb1}', '\u{eb1}'), ('\u{eb4}', '\u{ebc}'), ('\u{ec8}', '\u{ece}'),
+    ('\u{f18}', '\u{f19}'), ('\u{f35}', '\u{f35}'), ('\u{f37}', '\u{f37}'), ('\u{f39}', '\u{f39}'),
+    ('\u{f3e}', '\u{f3f}'), ('\u{f71}', '\u{f84}'), ('\u{f86}', '\u{f87}'), ('\u{f8d}', '\u{f97}'),
+    ('\u{f99}', '\u{fbc}'), ('\u{fc6}', '\u{fc6}'), ('\u{102b}', '\u{103e}'),
+    ('\u{1056}', '\u{1059}'), ('\u{105e}', '\u{1060}'), ('\u{1062}', '\u{1064}'),
+    ('\u{1067}', '\u{106d}'), ('\u{1071}', '\u{1074}'), ('\u{1082}', '\u{108d}'),
+    ('\u{108f}', '\u{108f}'), ('\u{109a}', '\u{109d}'), ('\u{135d}', '\u{135f}'),
+    ('\u{1712}', '\u{1715}'), ('\u{1732}', '\u{1734}'), ('\u{1752}', '\u{1753}'),
+    ('\u{1772}', '\u{1773}'), ('\u{17b4}', '\u{17d3}'), ('\u{17dd}', '\u{17dd}'),
+    ('\u{180b}', '\u{180d}'), ('\u{180f}', '\u{180f}'), ('\u{1885}', '\u{1886}'),
+    ('\u{18a9}', '\u{18a9}'), ('\u{1920}', '\u{192b}'), ('\u{1930}', '\u{193b}'),
+    ('\u{1a17}', '\u{1a1b}'), ('\u{1a55}', '\u{1a5e}'), ('\u{1a60}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a7f}'), ('\u{1ab0}', '\u{1ace}'), ('\u{1b00}', '\u{1b04}'),
+    ('\u{1b34}', '\u{1b44}'), ('\u{1b6b}', '\u{1b73}'), ('\u{1b80}', '\u{1b82}'),
+    ('\u{1ba1}', '\u{1bad}'), ('\u{1be6}', '\u{1bf3}'), ('\u{1c24}', '\u{1c37}'),
+    ('\u{1cd0}', '\u{1cd2}'), ('\u{1cd4}', '\u{1ce8}'), ('\u{1ced}', '\u{1ced}'),
+    ('\u{1cf4}', '\u{1cf4}'), ('\u{1cf7}', '\u{1cf9}'), ('\u{1dc0}', '\u{1dff}'),
+    ('\u{20d0}', '\u{20f0}'), ('\u{2cef}', '\u{2cf1}'), ('\u{2d7f}', '\u{2d7f}'),
+    ('\u{2de0}', '\u{2dff}'), ('\u{302a}', '\u{302f}'), ('\u{3099}', '\u{309a}'),
+    ('\u{a66f}', '\u{a672}'), ('\u{a674}', '\u{a67d}'), ('\u{a69e}', '\u{a69f}'),
+    ('\u{a6f0}', '\u{a6f1}'), ('\u{a802}', '\u{a802}'), ('\u{a806}', '\u{a806}'),
+    ('\u{a80b}', '\u{a80b}'), ('\u{a823}', '\u{a827}'), ('\u{a82c}', '\u{a82c}'),
+    ('\u{a880}', '\u{a881}'), ('\u{a8b4}', '\u{a8c5}'), ('\u{a8e0}', '\u{a8f1}'),
+    ('\u{a8ff}', '\u{a8ff}'), ('\u{a926}', '\u{a92d}'), ('\u{a947}', '\u{a953}'),
+    ('\u{a980}', '\u{a983}'), ('\u{a9b3}', '\u{a9c0}'), ('\u{a9e5}', '\u{a9e5}'),
+    ('\u{aa29}', '\u{aa36}'), ('\u{aa43}', '\u{aa43}'), ('\u{aa4c}', '\u{aa4d}'),
+    ('\u{aa7b}', '\u{aa7d}'), ('\u{aab0}', '\u{aab0}'), ('\u{aab2}', '\u{aab4}'),
+    ('\u{aab7}', '\u{aab8}'), ('\u{aabe}', '\u{aabf}'), ('\u{aac1}', '\u{aac1}'),
+    ('\u{aaeb}', '\u{aaef}'), ('\u{aaf5}', '\u{aaf6}'), ('\u{abe3}', '\u{abea}'),
+    ('\u{abec}', '\u{abed}'), ('\u{fb1e}', '\u{fb1e}'), ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'), ('\u{101fd}', '\u{101fd}'), ('\u{102e0}', '\u{102e0}'),
+    ('\u{10376}', '\u{1037a}'), ('\u{10a01}', '\u{10a03}'), ('\u{10a05}', '\u{10a06}'),
+    ('\u{10a0c}', '\u{10a0f}'), ('\u{10a38}', '\u{10a3a}'), ('\u{10a3f}', '\u{10a3f}'),
+    ('\u{10ae5}', '\u{10ae6}'), ('\u{10d24}', '\u{10d27}'), ('\u{10d69}', '\u{10d6d}'),
+    ('\u{10eab}', '\u{10eac}'), ('\u{10efc}', '\u{10eff}'), ('\u{10f46}', '\u{10f50}'),
+    ('\u{10f82}', '\u{10f85}'), ('\u{11000}', '\u{11002}'), ('\u{11038}', '\u{11046}'),
+    ('\u{11070}', '\u{11070}'), ('\u{11073}', '\u{11074}'), ('\u{1107f}', '\u{11082}'),
+    ('\u{110b0}', '\u{110ba}'), ('\u{110c2}', '\u{110c2}'), ('\u{11100}', '\u{11102}'),
+    ('\u{11127}', '\u{11134}'), ('\u{11145}', '\u{11146}'), ('\u{11173}', '\u{11173}'),
+    ('\u{11180}', '\u{11182}'), ('\u{111b3}', '\u{111c0}'), ('\u{111c9}', '\u{111cc}'),
+    ('\u{111ce}', '\u{111cf}'), ('\u{1122c}', '\u{11237}'), ('\u{1123e}', '\u{1123e}'),
+    ('\u{11241}', '\u{11241}'), ('\u{112df}', '\u{112ea}'), ('\u{11300}', '\u{11303}'),
+    ('\u{1133b}', '\u{1133c}'), ('\u{1133e}', '\u{11344}'), ('\u{11347}', '\u{11348}'),
+    ('\u{1134b}', '\u{1134d}'), ('\u{11357}', '\u{11357}'), ('\u{11362}', '\u{11363}'),
+    ('\u{11366}', '\u{1136c}'), ('\u{11370}', '\u{11374}'), ('\u{113b8}', '\u{113c0}'),
+    ('\u{113c2}', '\u{113c2}'), ('\u{113c5}', '\u{113c5}'), ('\u{113c7}', '\u{113ca}'),
+    ('\u{113cc}', '\u{113d0}'), ('\u{113d2}', '\u{113d2}'), ('\u{113e1}', '\u{113e2}'),
+    ('\u{11435}', '\u{11446}'), ('\u{1145e}', '\u{1145e}'), ('\u{114b0}', '\u{114c3}'),
+    ('\u{115af}', '\u{115b5}'), ('\u{115b8}', '\u{115c0}'), ('\u{115dc}', '\u{115dd}'),
+    ('\u{11630}', '\u{11640}'), ('\u{116ab}', '\u{116b7}'), ('\u{1171d}', '\u{1172b}'),
+    ('\u{1182c}', '\u{1183a}'), ('\u{11930}', '\u{11935}'), ('\u{11937}', '\u{11938}'),
+    ('\u{1193b}', '\u{1193e}'), ('\u{11940}', '\u{11940}'), ('\u{11942}', '\u{11943}'),
+    ('\u{119d1}', '\u{119d7}'), ('\u{119da}', '\u{119e0}'), ('\u{119e4}', '\u{119e4}'),
+    ('\u{11a01}', '\u{11a0a}'), ('\u{11a33}', '\u{11a39}'), ('\u{11a3b}', '\u{11a3e}'),
+    ('\u{11a47}', '\u{11a47}'), ('\u{11a51}', '\u{11a5b}'), ('\u{11a8a}', '\u{11a99}'),
+    ('\u{11c2f}', '\u{11c36}'), ('\u{11c38}', '\u{11c3f}'), ('\u{11c92}', '\u{11ca7}'),
+    ('\u{11ca9}', '\u{11cb6}'), ('\u{11d31}', '\u{11d36}'), ('\u{11d3a}', '\u{11d3a}'),
+    ('\u{11d3c}', '\u{11d3d}'), ('\u{11d3f}', '\u{11d45}'), ('\u{11d47}', '\u{11d47}'),
+    ('\u{11d8a}', '\u{11d8e}'), ('\u{11d90}', '\u{11d91}'), ('\u{11d93}', '\u{11d97}'),
+    ('\u{11ef3}', '\u{11ef6}'), ('\u{11f00}', '\u{11f01}'), ('\u{11f03}', '\u{11f03}'),
+    ('\u{11f34}', '\u{11f3a}'), ('\u{11f3e}', '\u{11f42}'), ('\u{11f5a}', '\u{11f5a}'),
+    ('\u{13440}', '\u{13440}'), ('\u{13447}', '\u{13455}'), ('\u{1611e}', '\u{1612f}'),
+    ('\u{16af0}', '\u{16af4}'), ('\u{16b30}', '\u{16b36}'), ('\u{16f4f}', '\u{16f4f}'),
+    ('\u{16f51}', '\u{16f87}'), ('\u{16f8f}', '\u{16f92}'), ('\u{16fe4}', '\u{16fe4}'),
+    ('\u{16ff0}', '\u{16ff1}'), ('\u{1bc9d}', '\u{1bc9e}'), ('\u{1cf00}', '\u{1cf2d}'),
+    ('\u{1cf30}', '\u{1cf46}'), ('\u{1d165}', '\u{1d169}'), ('\u{1d16d}', '\u{1d172}'),
+    ('\u{1d17b}', '\u{1d182}'), ('\u{1d185}', '\u{1d18b}'), ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{1d242}', '\u{1d244}'), ('\u{1da00}', '\u{1da36}'), ('\u{1da3b}', '\u{1da6c}'),
+    ('\u{1da75}', '\u{1da75}'), ('\u{1da84}', '\u{1da84}'), ('\u{1da9b}', '\u{1da9f}'),
+    ('\u{1daa1}', '\u{1daaf}'), ('\u{1e000}', '\u{1e006}'), ('\u{1e008}', '\u{1e018}'),
+    ('\u{1e01b}', '\u{1e021}'), ('\u{1e023}', '\u{1e024}'), ('\u{1e026}', '\u{1e02a}'),
+    ('\u{1e08f}', '\u{1e08f}'), ('\u{1e130}', '\u{1e136}'), ('\u{1e2ae}', '\u{1e2ae}'),
+    ('\u{1e2ec}', '\u{1e2ef}'), ('\u{1e4ec}', '\u{1e4ef}'), ('\u{1e5ee}', '\u{1e5ef}'),
+    ('\u{1e8d0}', '\u{1e8d6}'), ('\u{1e944}', '\u{1e94a}'), ('\u{e0100}', '\u{e01ef}'),
+];
@@ -0,0 +1,55 @@
+//! What can go wrong segmenting, for the `try_` entry points to return rather than panic on.
+
+use std::fmt;
+
+use crate::rules::ResolverProcessingRule;
+
+/// Why a backend could not segment under the rules it was given.
+///
+/// Every entry point without `try_` in its name panics with this instead, so a ruleset built from
+/// configuration is best checked once through [`Segmenter::try_new`](crate::segmenter::Segmenter)
+/// or one of the `try_` methods before it is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SegmentationError {
+    /// A rule the backend has no reading of, such as `Remove(Numerics, All)` for the regex
+    /// backends, which only remove characters, strings, acronyms and punctuation.
+    UnsupportedRule {
+        backend: &'static str,
+        rule: ResolverProcessingRule,
+    },
+    /// A pattern built from the rules that does not compile, usually because of a character in
+    /// them that means something to the regex syntax.
+    InvalidPattern {
+        backend: &'static str,
+        pattern: String,
+        reason: String,
+    },
+    /// A pattern that compiled but could not be matched against the input, as when `fancy_regex`
+    /// gives up backtracking.
+    MatchFailed {
+        backend: &'static str,
+        reason: String,
+    },
+}
+
+impl fmt::Display for SegmentationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SegmentationError::UnsupportedRule { backend, rule } => {
+                write!(f, "the {} backend does not support the rule {:?}", backend, rule)
+            },
+            SegmentationError::InvalidPattern { backend, pattern, reason } => {
+                write!(
+                    f,
+                    "the {} backend built an invalid pattern {:?}: {}",
+                    backend, pattern, reason
+                )
+            },
+            SegmentationError::MatchFailed { backend, reason } => {
+                write!(f, "the {} backend failed to match the input: {}", backend, reason)
+            },
+        }
+    }
+}
+
+impl std::error::Error for SegmentationError {}
@@ -1,9 +1,27 @@
+use std::borrow::Cow;
+use std::iter::{self, Empty, Peekable};
 use std::marker::PhantomData;
+use std::ops::Range;
+#[cfg(not(feature = "enhanced_accuracy"))]
+use std::str::CharIndices;
+use std::sync::Arc;
+use std::vec;
 
+use aho_corasick::{AhoCorasick, Input, Match};
+#[cfg(feature = "enhanced_accuracy")]
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+use crate::capabilities::{Capabilities, TargetKind};
+#[cfg(not(feature = "enhanced_accuracy"))]
+use crate::categories;
+#[cfg(feature = "enhanced_accuracy")]
+use crate::impls::clusters;
 use crate::impls::compiled::Compiled;
-use crate::rules::{DefaultRules, ResolverRules};
+use crate::impls::passes::{self, Located, Passed};
+use crate::rules::{DefaultRules, ResolverRules, RuleSet};
+use crate::segment::{CharCursor, Segment};
 use crate::CompiledRules::NotApplicable;
-use crate::{CompiledRules, WordBoundResolverImpl, __str_ext__instance_words_vec};
+use crate::{CompiledRules, WordBoundResolverImpl};
 
 macro_rules! __str_ext__impl_parsing_for_target {
     ($target:expr, $predicate:expr, $rules:ident, $del_flag:ident, $commit_flag:ident,
@@ -16,20 +34,17 @@ macro_rules! __str_ext__impl_parsing_for_target {
     $bstart:ident, $bend:ident,
     $is_first:expr, $is_last:expr, { $($extras:tt)* } ) => {
         if !$target.is_inert() && $predicate && !$del_flag {
+            // removals scoped to a word wait for the word to be finished, in `Walk::commit`
             if $target.remove_all {
                 $del_flag = true;
             } else if $target.remove_middle_input {
                 if !$is_first && !$is_last {
                     $del_flag = true;
                 }
-            } else if $target.remove_middle_word {
-                unimplemented!();
             } else if $target.remove_ends_input {
                 if $is_first || $is_last {
                     $del_flag = true;
                 }
-            } else if $target.remove_ends_word {
-                unimplemented!();
             }
             if $target.bound_start {
                 $bstart = true;
@@ -54,23 +69,63 @@ fn is_digit(c: char) -> bool {
     }
 }
 
-/// Whether `c` is upper case, ASCII answered without the tables.
-#[inline]
-fn is_upper(c: char) -> bool {
-    if c.is_ascii() {
-        c.is_ascii_uppercase()
-    } else {
-        c.is_uppercase()
-    }
+/// The word being built, kept as a range of the input for as long as it is one.
+///
+/// Every character the walk keeps lands directly after the one before it, unless a removal rule
+/// dropped something in between without bounding the word there. Only then is there no slice of
+/// the input that reads as the word, and only then is it copied out.
+struct Pending<'a> {
+    input: &'a str,
+    start: usize,
+    end: usize,
+    owned: Option<String>,
 }
 
-/// Whether `c` is lower case, ASCII answered without the tables.
-#[inline]
-fn is_lower(c: char) -> bool {
-    if c.is_ascii() {
-        c.is_ascii_lowercase()
-    } else {
-        c.is_lowercase()
+impl<'a> Pending<'a> {
+    fn new(input: &'a str) -> Self {
+        Pending {
+            input,
+            start: 0,
+            end: 0,
+            owned: None,
+        }
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Adds the input's `at..end`, one character or one cluster of them.
+    #[inline]
+    fn push(&mut self, at: usize, end: usize) {
+        if self.is_empty() {
+            self.start = at;
+        } else if at != self.end && self.owned.is_none() {
+            self.owned = Some(self.input[self.start..self.end].to_owned());
+        }
+        if let Some(owned) = &mut self.owned {
+            owned.push_str(&self.input[at..end]);
+        }
+        self.end = end;
+    }
+
+    /// Whether the last thing added ended at `end`.
+    #[inline]
+    fn ends_at(&self, end: usize) -> bool {
+        !self.is_empty() && self.end == end
+    }
+
+    /// Hands the word over with its extent in the input, and starts the next one empty.
+    #[inline]
+    fn take(&mut self) -> (Cow<'a, str>, Range<usize>) {
+        let word = match self.owned.take() {
+            Some(owned) => Cow::Owned(owned),
+            None => Cow::Borrowed(&self.input[self.start..self.end]),
+        };
+        let range = self.start..self.end;
+        self.start = self.end;
+        (word, range)
     }
 }
 
@@ -79,177 +134,659 @@ pub struct Charwalk<R: ResolverRules = DefaultRules> {
 }
 
 impl<R: ResolverRules> WordBoundResolverImpl<R> for Charwalk<R> {
-    fn resolver(s: &str) -> Vec<String> {
-        __str_ext__instance_words_vec!(s, words);
-        // dbg!(s);
-
-        let punct_chars = R::punct_chars_non_regex();
-        let non_punct_special_chars = R::non_punct_special_chars_non_regex();
-        let rule_list = R::resolution_pass_rules();
-        let rules = Compiled::new(&rule_list, &punct_chars, &non_punct_special_chars);
-
-        let mut prev_char: Option<char> = None;
-        let mut prev_prev_char: Option<char> = None;
-        let mut prev_committed_char: Option<char> = None;
-        let mut next_char: Option<char> = None;
+    type Iter<'a> = Passed<'a, Walk<'a>>;
+    type Prepared = Arc<Compiled>;
+
+    fn prepare(rules: &RuleSet) -> Arc<Compiled> {
+        Arc::new(Compiled::of(rules))
+    }
+
+    fn iter_prepared<'a>(prepared: &Arc<Compiled>, s: &'a str) -> Self::Iter<'a> {
+        Passed::new(prepared, s, Walk::new(s, Arc::clone(prepared)))
+    }
+
+    /// Everything but whole words, which only exist once resolution is done.
+    fn capabilities() -> Capabilities {
+        Self::capabilities_for(&RuleSet::of::<R>())
+    }
+
+    /// Lazy unless the passes around the walk read all of the input.
+    fn capabilities_for(rules: &RuleSet) -> Capabilities {
+        Capabilities {
+            backend: "charwalk",
+            lazy: !passes::reads_ahead(&Compiled::of(rules)),
+            removes: TargetKind::WITHIN_WORDS,
+            remove_modes: TargetKind::WITHIN_WORDS,
+            refuses: false,
+            bound_starts: TargetKind::WITHIN_WORDS,
+            bound_ends: TargetKind::WITHIN_WORDS,
+            one_sided_runs: true,
+            whole_strings: true,
+        }
+    }
+
+    fn compile_rule_set(_rules: &RuleSet) -> CompiledRules {
+        NotApplicable
+    }
+}
+
+/// What the walk reads as one character: where it starts in the input, the character, and where
+/// it ends. That is one `char`, or under `enhanced_accuracy` one grapheme cluster, read as the
+/// character it starts with.
+type Unit = (usize, char, usize);
+
+/// The characters the walk reads: the input's, or what the pre-pass left of them.
+enum Source<'a> {
+    #[cfg(not(feature = "enhanced_accuracy"))]
+    Input(CharIndices<'a>),
+    #[cfg(feature = "enhanced_accuracy")]
+    Clusters(GraphemeIndices<'a>),
+    Kept(vec::IntoIter<Unit>),
+}
+
+impl<'a> Iterator for Source<'a> {
+    type Item = Unit;
+
+    #[inline]
+    fn next(&mut self) -> Option<Unit> {
+        match self {
+            #[cfg(not(feature = "enhanced_accuracy"))]
+            Source::Input(chars) => {
+                let first = chars.next()?;
+                Some(unit(first, chars))
+            },
+            #[cfg(feature = "enhanced_accuracy")]
+            Source::Clusters(clusters) => clusters.next().map(cluster),
+            Source::Kept(chars) => chars.next(),
+        }
+    }
+}
+
+/// The character `first`, with what follows it in `rest` and joins it, as the walk reads them.
+///
+/// Without `enhanced_accuracy` there are no clusters to read, so this keeps together what a rule
+/// must not part: a character and the marks on it, such as the selector that shows `⚠️` as an
+/// emoji, and the emoji a joiner, a skin tone or a flag's second letter makes one of. Only what
+/// lies next to it in the input joins a character.
+#[cfg(not(feature = "enhanced_accuracy"))]
+#[inline]
+fn unit(first: Located, rest: &mut (impl Iterator<Item = Located> + Clone)) -> Unit {
+    let (at, c) = first;
+    let (mut prev, mut end, mut paired) = (c, at + c.len_utf8(), false);
+    while let Some((next_at, next)) = rest.clone().next() {
+        if next_at != end || next.is_ascii() || !joins(prev, next, paired) {
+            break;
+        }
+        rest.next();
+        paired = !paired && is_regional(prev) && is_regional(next);
+        prev = next;
+        end = next_at + next.len_utf8();
+    }
+    (at, c, end)
+}
+
+/// Whether `c` joins the character before it, `prev`, which is the second of a pair of regional
+/// indicators where `paired`.
+#[cfg(not(feature = "enhanced_accuracy"))]
+#[inline]
+fn joins(prev: char, c: char, paired: bool) -> bool {
+    const JOINER: char = '\u{200d}';
+    prev == JOINER
+        || c == JOINER
+        || ('\u{1f3fb}'..='\u{1f3ff}').contains(&c)
+        || (!paired && is_regional(prev) && is_regional(c))
+        || categories::is_mark(c)
+}
+
+/// Whether `c` is a regional indicator, half of a flag.
+#[cfg(not(feature = "enhanced_accuracy"))]
+#[inline]
+fn is_regional(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+/// A cluster of the input as the walk reads it.
+#[cfg(feature = "enhanced_accuracy")]
+#[inline]
+fn cluster((at, text): (usize, &str)) -> Unit {
+    let c = text.chars().next().expect("a grapheme cluster has a character");
+    (at, c, at + text.len())
+}
+
+impl<'a> Source<'a> {
+    fn new(s: &'a str, kept: Option<Vec<Located>>) -> Self {
+        #[cfg(feature = "enhanced_accuracy")]
+        match kept {
+            Some(kept) => Source::Kept(clusters::units(&kept).into_iter()),
+            None => Source::Clusters(s.grapheme_indices(true)),
+        }
+        #[cfg(not(feature = "enhanced_accuracy"))]
+        match kept {
+            Some(kept) => {
+                let mut kept = kept.into_iter();
+                let mut units = Vec::with_capacity(kept.len());
+                while let Some(first) = kept.next() {
+                    units.push(unit(first, &mut kept));
+                }
+                Source::Kept(units.into_iter())
+            },
+            None => Source::Input(s.char_indices()),
+        }
+    }
+
+    /// The next character, left to be read.
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        match self {
+            #[cfg(not(feature = "enhanced_accuracy"))]
+            Source::Input(chars) => chars.clone().next().map(|(_, c)| c),
+            #[cfg(feature = "enhanced_accuracy")]
+            Source::Clusters(clusters) => clusters.clone().next().map(|next| cluster(next).1),
+            Source::Kept(chars) => chars.as_slice().first().map(|&(_, c, _)| c),
+        }
+    }
+
+    /// The length of the acronym `c` starts, `c` being the character just read, and whether
+    /// nothing is left to read after it.
+    fn acronym(&self, rules: &Compiled, c: char) -> Option<(usize, bool)> {
+        fn ahead(
+            rules: &Compiled,
+            c: char,
+            rest: impl Iterator<Item = char> + Clone,
+        ) -> Option<(usize, bool)> {
+            let len = rules.acronym_at(std::iter::once(c).chain(rest.clone()))?;
+            Some((len, rest.clone().nth(len - 1).is_none()))
+        }
+        match self {
+            #[cfg(not(feature = "enhanced_accuracy"))]
+            Source::Input(chars) => ahead(rules, c, chars.clone().map(|(_, c)| c)),
+            #[cfg(feature = "enhanced_accuracy")]
+            Source::Clusters(clusters) => {
+                ahead(rules, c, clusters.clone().map(|next| cluster(next).1))
+            },
+            Source::Kept(chars) => ahead(rules, c, chars.as_slice().iter().map(|&(_, c, _)| c)),
+        }
+    }
+}
+
+/// The acronym being walked: its length, how many of its characters are yet to be walked, and
+/// whether nothing the walk reads comes before it, and after it.
+#[derive(Clone, Copy, Default)]
+struct Acronym {
+    len: usize,
+    left: usize,
+    leads: bool,
+    trails: bool,
+}
+
+/// Where a string target was found, by the positions in the input of its first and last
+/// characters.
+#[derive(Clone, Copy)]
+struct StringMatch {
+    pattern: usize,
+    first: usize,
+    last: usize,
+    /// Whether nothing the walk reads comes before it, and after it.
+    leads: bool,
+    trails: bool,
+}
+
+/// The string targets in what the walk reads, found as the walk reaches them.
+///
+/// Each search runs from where the last match ended to as far past the character being read as the
+/// longest string reaches. A string starting at or before that character ends inside that window,
+/// so what the search finds there is what a search of everything would, and nothing further on is
+/// read.
+///
+/// With a pre-pass the search is over what it kept, so that a string it closed up is found, and
+/// the matches are mapped back to the input through the kept characters' positions.
+struct Strings<'a> {
+    text: Cow<'a, str>,
+    /// Where each kept character starts in `text`, and in the input, where there is a pre-pass.
+    kept: Option<Vec<(usize, usize)>>,
+    longest: usize,
+    /// Where in `text` the next search starts.
+    from: usize,
+    found: Option<StringMatch>,
+}
+
+impl<'a> Strings<'a> {
+    fn new(matcher: &AhoCorasick, s: &'a str, kept: Option<&[Located]>) -> Self {
+        let (text, kept) = match kept {
+            None => (Cow::Borrowed(s), None),
+            Some(kept) => {
+                let mut text = String::with_capacity(kept.len());
+                let mut offsets = Vec::with_capacity(kept.len());
+                for &(at, c) in kept {
+                    offsets.push((text.len(), at));
+                    text.push(c);
+                }
+                (Cow::Owned(text), Some(offsets))
+            },
+        };
+        Strings {
+            text,
+            kept,
+            longest: matcher.max_pattern_len(),
+            from: 0,
+            found: None,
+        }
+    }
+
+    /// The string the character at `at..end` in the input is part of, if it is part of one.
+    fn at(&mut self, matcher: &AhoCorasick, at: usize, end: usize) -> Option<StringMatch> {
+        if self.found.is_some_and(|found| found.last < at) {
+            self.found = None;
+        }
+        if self.found.is_none() {
+            // where the character ends in what is searched
+            let stop = match &self.kept {
+                None => end,
+                Some(kept) => {
+                    let idx = kept.partition_point(|&(_, kept_at)| kept_at < end);
+                    kept.get(idx).map_or(self.text.len(), |&(offset, _)| offset)
+                },
+            };
+            if self.from < stop {
+                let window = self.from..(stop + self.longest).min(self.text.len());
+                match matcher.find(Input::new(&*self.text).span(window)) {
+                    Some(found) if found.start() < stop => {
+                        self.from = found.end();
+                        self.found = Some(self.located(found));
+                    },
+                    _ => self.from = stop,
+                }
+            }
+        }
+        self.found.filter(|found| found.first < end)
+    }
+
+    /// `found`, by where its characters are in the input.
+    fn located(&self, found: Match) -> StringMatch {
+        let (start, end) = (found.start(), found.end());
+        let last_len = self.text[..end].chars().next_back().map_or(0, char::len_utf8);
+        let pattern = found.pattern().as_usize();
+        match &self.kept {
+            None => StringMatch {
+                pattern,
+                first: start,
+                last: end - last_len,
+                leads: start == 0,
+                trails: end == self.text.len(),
+            },
+            Some(kept) => {
+                let index_of =
+                    |offset| kept.partition_point(|&(kept_offset, _)| kept_offset < offset);
+                let (first, last) = (index_of(start), index_of(end - last_len));
+                StringMatch {
+                    pattern,
+                    first: kept[first].1,
+                    last: kept[last].1,
+                    leads: first == 0,
+                    trails: last == kept.len() - 1,
+                }
+            },
+        }
+    }
+}
+
+/// The walk itself, advanced one character at a time as words are asked for.
+///
+/// Nothing is read past the character that finishes the word handed out, bar as far on as the
+/// longest string target reaches, so stopping early stops the walk: taking the first few words of a
+/// long input costs the first few words.
+///
+/// The pre-pass, where there is one, has read all of the input before the walk starts, and the walk
+/// reads only the characters it kept.
+///
+/// `B` is where a word ends whatever the rules say, as the positions in the input of the characters
+/// that start the next, ascending. Charwalk has none; the `unicode` backend takes them from
+/// UAX #29.
+pub struct Walk<'a, B: Iterator<Item = usize> = Empty<usize>> {
+    input: &'a str,
+    rules: Arc<Compiled>,
+    walk: Source<'a>,
+    strings: Option<Strings<'a>>,
+    breaks: Peekable<B>,
+    acronym: Acronym,
+    prev_char: Option<char>,
+    prev_in_string: bool,
+    curr_word: Pending<'a>,
+    chars: CharCursor<'a>,
+    /// One character finishes at most two words, the one before it and the one it is.
+    ready: [Option<Segment<'a>>; 2],
+}
+
+impl<'a> Walk<'a> {
+    pub(crate) fn new(s: &'a str, rules: Arc<Compiled>) -> Self {
+        Walk::with_breaks(s, rules, |_, _| iter::empty())
+    }
+}
+
+impl<'a, B: Iterator<Item = usize>> Walk<'a, B> {
+    /// A walk that also ends a word before each position `breaks` gives, out of what the walk
+    /// reads: the input, or the characters the pre-pass kept of it where that is given.
+    pub(crate) fn with_breaks(
+        s: &'a str,
+        rules: Arc<Compiled>,
+        breaks: impl FnOnce(&'a str, Option<&[Located]>) -> B,
+    ) -> Self {
+        let kept = passes::pre_pass(&rules, s);
+        let strings = (rules.string_matcher.as_ref())
+            .map(|matcher| Strings::new(matcher, s, kept.as_deref()));
+        let breaks = breaks(s, kept.as_deref()).peekable();
+        let walk = Source::new(s, kept);
+        Walk {
+            input: s,
+            rules,
+            walk,
+            strings,
+            breaks,
+            acronym: Acronym::default(),
+            prev_char: None,
+            prev_in_string: false,
+            curr_word: Pending::new(s),
+            chars: CharCursor::new(s),
+            ready: [None, None],
+        }
+    }
+
+    #[inline]
+    fn commit(&mut self) {
+        let (word, range) = self.curr_word.take();
+        let kind = self.rules.kind_of(&word);
+        let mut segment = self.chars.segment(word, range, kind);
+        if !self.rules.within_word.is_empty() {
+            match passes::within_word(&self.rules, self.input, segment) {
+                Some(kept) => segment = kept,
+                None => return,
+            }
+        }
+        let slot = if self.ready[0].is_none() { 0 } else { 1 };
+        self.ready[slot] = Some(segment);
+    }
+
+    /// The string target the character at `at..end` is part of, if it is part of one.
+    #[inline]
+    fn string_at(&mut self, at: usize, end: usize) -> Option<StringMatch> {
+        let (Some(strings), Some(matcher)) = (&mut self.strings, &self.rules.string_matcher) else {
+            return None;
+        };
+        strings.at(matcher, at, end)
+    }
+
+    /// Whether a word ends before the character at `at` whatever the rules say.
+    #[inline]
+    fn breaks_at(&mut self, at: usize) -> bool {
+        while self.breaks.next_if(|&next| next < at).is_some() {}
+        self.breaks.next_if_eq(&at).is_some()
+    }
+
+    /// The acronym the character `c` just read is part of, if it is part of one.
+    #[inline]
+    fn acronym_at(&mut self, c: char) -> Option<Acronym> {
+        if self.acronym.left == 0 {
+            if self.rules.acronym.is_inert()
+                || !self.rules.is_upper(c)
+                || self.prev_char.is_some_and(|prev| self.rules.is_upper(prev))
+            {
+                return None;
+            }
+            let (len, trails) = self.walk.acronym(&self.rules, c)?;
+            self.acronym = Acronym {
+                len,
+                left: len,
+                leads: self.prev_char.is_none(),
+                trails,
+            };
+        }
+        let acronym = self.acronym;
+        self.acronym.left -= 1;
+        Some(acronym)
+    }
+
+    /// Walks one character, returning false once there are none left.
+    fn step(&mut self) -> bool {
+        let Some((at, c, end)) = self.walk.next() else {
+            return false;
+        };
+        let string = self.string_at(at, end);
+        // not at or inside a string, whose characters answer to its rules alone
+        let breaks = self.breaks_at(at) && string.is_none() && !self.prev_in_string;
+        if breaks && !self.curr_word.is_empty() {
+            self.commit();
+        }
+        let acronym = self.acronym_at(c);
+        let rules = &self.rules;
+        let prev_char = self.prev_char;
         let mut flag_to_commit = false;
-        let mut curr_word = String::new();
-        let mut _prev_was_split: i8 = 0;
-        let prev_was_split = || _prev_was_split == 1;
         let mut flag_to_delete = false;
         let mut bound_start: bool = false;
         let mut bound_end: bool = false;
+        let next_char = self.walk.peek();
+        let is_first = prev_char.is_none();
+        let is_last = next_char.is_none();
 
-        let mut walk = s.chars().peekable();
-        let mut idx = 0usize;
-        while let Some(c) = walk.next() {
-            flag_to_commit = false;
-            flag_to_delete = false;
-            bound_start = false;
-            bound_end = false;
-            next_char = walk.peek().copied();
-            let is_first = idx == 0;
-            let is_last = next_char.is_none();
-
-            macro_rules! impl_parsing_for {
-                ($target:expr, $predicate:expr) => {
-                    __str_ext__impl_parsing_for_target!(
-                        $target,
-                        $predicate,
-                        rules,
-                        flag_to_delete,
-                        flag_to_commit,
-                        bound_start,
-                        bound_end,
-                        is_first,
-                        is_last
-                    );
-                };
-                ($target:expr, $predicate:expr, { $extras:tt }) => {
-                    __str_ext__impl_parsing_for_target!(
-                        $target,
-                        $predicate,
-                        rules,
-                        flag_to_delete,
-                        flag_to_commit,
-                        bound_start,
-                        bound_end,
-                        is_first,
-                        is_last,
-                        $extras
-                    );
-                };
-            }
+        macro_rules! impl_parsing_for {
+            ($target:expr, $predicate:expr) => {
+                __str_ext__impl_parsing_for_target!(
+                    $target,
+                    $predicate,
+                    rules,
+                    flag_to_delete,
+                    flag_to_commit,
+                    bound_start,
+                    bound_end,
+                    is_first,
+                    is_last
+                );
+            };
+            ($target:expr, $predicate:expr, { $extras:tt }) => {
+                __str_ext__impl_parsing_for_target!(
+                    $target,
+                    $predicate,
+                    rules,
+                    flag_to_delete,
+                    flag_to_commit,
+                    bound_start,
+                    bound_end,
+                    is_first,
+                    is_last,
+                    $extras
+                );
+            };
+        }
 
-            let same_before = prev_char == Some(c);
-            let same_after = next_char == Some(c);
-            let punct_run = rules.punct.contains(c) && (same_before || same_after);
-            let (in_run, run_starts, run_ends) = (
-                punct_run,
-                punct_run && !same_before,
-                punct_run && !same_after,
-            );
-            impl_parsing_for!(rules.punct_run, in_run, {
+        // first, so that what it leaves unbounded inside the acronym is only what it bounded
+        let within_acronym = acronym.is_some_and(|acronym| acronym.left != acronym.len);
+        if let Some(found) = acronym {
+            let (starts, ends) = (found.left == found.len, found.left == 1);
+            __str_ext__impl_parsing_for_target!(
+                rules.acronym,
+                true,
+                rules,
+                flag_to_delete,
+                flag_to_commit,
+                bound_start,
+                bound_end,
+                found.leads,
+                found.trails,
                 {
-                    // the run bounds the token it forms, not each character inside it
-                    if !run_starts {
-                        bound_start = false;
+                    {
+                        if !starts {
+                            bound_start = false;
+                        }
+                        if !ends {
+                            bound_end = false;
+                        }
+                        if !bound_start && !bound_end {
+                            flag_to_commit = false;
+                        }
                     }
-                    if !run_ends {
-                        bound_end = false;
+                }
+            );
+        }
+
+        let same_before = prev_char == Some(c);
+        let same_after = next_char == Some(c);
+        let punct_run = rules.punct.contains(c) && (same_before || same_after);
+        let (in_run, run_starts, run_ends) = (
+            punct_run,
+            punct_run && !same_before,
+            punct_run && !same_after,
+        );
+        impl_parsing_for!(rules.punct_run, in_run, {
+            {
+                // the run bounds the token it forms, not each character inside it
+                if !run_starts {
+                    bound_start = false;
+                }
+                if !run_ends {
+                    bound_end = false;
+                }
+                if !run_starts && !run_ends {
+                    flag_to_commit = false;
+                }
+            }
+        });
+        impl_parsing_for!(rules.punct_char, !in_run && rules.punct.contains(c));
+        impl_parsing_for!(rules.numerics, is_digit(c), {
+            {
+                if (prev_char.is_some() && is_digit(prev_char.unwrap()))
+                    && (next_char.is_some() && is_digit(next_char.unwrap()))
+                {
+                    flag_to_commit = false;
+                    bound_start = false;
+                    bound_end = false;
+                } else if prev_char.map_or(true, |prev| !is_digit(prev))
+                    && next_char.map_or(true, is_digit)
+                {
+                    // the first digit of a run, the input's first included
+                    bound_end = false;
+                    if rules.numerics.bound_start {
+                        bound_start = true;
                     }
-                    if !run_starts && !run_ends {
-                        flag_to_commit = false;
+                } else if (prev_char.is_some() && is_digit(prev_char.unwrap()))
+                    && ((next_char.is_some() && !is_digit(next_char.unwrap()))
+                        || next_char.is_none())
+                {
+                    bound_start = false;
+                    if rules.numerics.bound_end {
+                        bound_end = true;
                     }
                 }
-            });
-            impl_parsing_for!(rules.punct_char, !in_run && rules.punct.contains(c));
-            impl_parsing_for!(rules.numerics, is_digit(c), {
+            }
+        });
+        for (inner_c, char_rules) in &rules.chars {
+            impl_parsing_for!(*char_rules, c == *inner_c, { {} });
+        }
+        impl_parsing_for!(rules.non_punct_special_rules, rules.non_punct_special.contains(c));
+        impl_parsing_for!(
+            rules.case_change,
+            !within_acronym
+                && prev_char.is_some()
+                && ((rules.is_upper(prev_char.unwrap())
+                    && (next_char.is_some() && rules.is_lower(next_char.unwrap()))
+                    && rules.starts_word(c))
+                    || (rules.is_lower(prev_char.unwrap()) && rules.starts_word(c)))
+        );
+        if let Some(found) = string {
+            // the characters of a string answer to its rules alone, not to what each is by itself
+            flag_to_delete = false;
+            flag_to_commit = false;
+            bound_start = false;
+            bound_end = false;
+            let (starts, ends) = (found.first >= at, found.last < end);
+            __str_ext__impl_parsing_for_target!(
+                rules.strings[found.pattern].1,
+                true,
+                rules,
+                flag_to_delete,
+                flag_to_commit,
+                bound_start,
+                bound_end,
+                found.leads,
+                found.trails,
                 {
-                    if (prev_char.is_some() && is_digit(prev_char.unwrap()))
-                        && (next_char.is_some() && is_digit(next_char.unwrap()))
                     {
-                        flag_to_commit = false;
-                        bound_start = false;
-                        bound_end = false;
-                    } else if (prev_char.is_some() && !is_digit(prev_char.unwrap()))
-                        && ((next_char.is_some() && is_digit(next_char.unwrap()))
-                            || next_char.is_none())
-                    {
-                        bound_end = false;
-                        if rules.numerics.bound_start {
-                            bound_start = true;
+                        // bounded as a whole, where it starts and where it ends
+                        if !starts {
+                            bound_start = false;
                         }
-                    } else if (prev_char.is_some() && is_digit(prev_char.unwrap()))
-                        && ((next_char.is_some() && !is_digit(next_char.unwrap()))
-                            || next_char.is_none())
-                    {
-                        bound_start = false;
-                        if rules.numerics.bound_end {
-                            bound_end = true;
+                        if !ends {
+                            bound_end = false;
+                        }
+                        if !bound_start && !bound_end {
                             flag_to_commit = false;
                         }
                     }
                 }
-            });
-            for (inner_c, char_rules) in &rules.chars {
-                impl_parsing_for!(*char_rules, c == *inner_c, { {} });
-            }
-            impl_parsing_for!(rules.non_punct_special_rules, rules.non_punct_special.contains(c));
-            impl_parsing_for!(
-                rules.case_change,
-                prev_char.is_some()
-                    && ((is_upper(prev_char.unwrap())
-                        && (next_char.is_some() && is_lower(next_char.unwrap()))
-                        && is_upper(c))
-                        || (is_lower(prev_char.unwrap()) && is_upper(c)))
             );
+        }
 
-            // process
+        // process
 
-            if !flag_to_delete && (!flag_to_commit || (flag_to_commit && bound_end)) {
-                prev_committed_char = Some(c);
-                if !bound_start {
-                    curr_word.push(c);
+        if !flag_to_delete && (!flag_to_commit || bound_end) && !bound_start {
+            self.curr_word.push(at, end);
+        }
+        if flag_to_commit && !self.curr_word.is_empty() {
+            self.commit();
+        }
+        if !flag_to_delete && flag_to_commit && bound_start {
+            self.curr_word.push(at, end);
+        }
+        if is_last || (!flag_to_delete && bound_start && bound_end) {
+            // a character that ended a token without starting one has already been committed
+            // above, so there is nothing left pending: the end of a punctuation run is the
+            // case that reaches here with an empty word in hand.
+            let already_committed = flag_to_commit && bound_end && !bound_start && !flag_to_delete;
+            if !already_committed {
+                if !flag_to_delete && !self.curr_word.ends_at(end) {
+                    self.curr_word.push(at, end);
                 }
-            }
-            if flag_to_commit {
-                if !curr_word.is_empty() {
-                    words.push(curr_word.to_lowercase());
-                    curr_word.clear();
+                if !self.curr_word.is_empty() {
+                    self.commit();
                 }
-                _prev_was_split = 2;
             }
-            if !flag_to_delete && flag_to_commit && bound_start {
-                prev_committed_char = Some(c);
-                curr_word.push(c);
+        }
+        self.prev_char = Some(c);
+        self.prev_in_string = string.is_some();
+        true
+    }
+}
+
+impl<'a, B: Iterator<Item = usize>> Iterator for Walk<'a, B> {
+    type Item = Segment<'a>;
+
+    fn next(&mut self) -> Option<Segment<'a>> {
+        loop {
+            if let Some(segment) = self.ready[0].take() {
+                self.ready.swap(0, 1);
+                return Some(segment);
             }
-            if is_last || (!flag_to_delete && bound_start && bound_end) {
-                // a character that ended a token without starting one has already been committed
-                // above, so there is nothing left pending: the end of a punctuation run is the
-                // case that reaches here with an empty word in hand.
-                let already_committed =
-                    flag_to_commit && bound_end && !bound_start && !flag_to_delete;
-                if !already_committed {
-                    if !flag_to_delete && !curr_word.ends_with(c) {
-                        curr_word.push(c);
-                    }
-                    if !curr_word.is_empty() {
-                        words.push(curr_word.to_lowercase());
-                        curr_word.clear();
-                    }
-                }
+            if !self.step() {
+                return None;
             }
-            prev_prev_char = prev_char;
-            prev_char = Some(c);
-            idx += 1;
-            _prev_was_split = if _prev_was_split < 1 { 0 } else { _prev_was_split - 1 };
         }
-
-        words
     }
+}
 
-    fn compile_rules() -> CompiledRules {
-        NotApplicable
+#[cfg(test)]
+mod tests {
+    use crate::rules::ResolverProcessingRule::BoundStart;
+    use crate::rules::RuleTarget::String;
+
+    use super::*;
+
+    #[test]
+    fn strings_are_searched_only_as_far_as_the_walk_reads() {
+        let mut rules = RuleSet::default();
+        rules.resolution_pass_rules.push(BoundStart(String("::".into())));
+        let s = "std::io ".repeat(1 << 12);
+        let mut walk = Walk::new(&s, Arc::new(Compiled::of(&rules)));
+        let words: Vec<_> = walk.by_ref().take(2).map(|segment| segment.text).collect();
+        assert_eq!(words, ["std", "::io"]);
+        let searched = walk.strings.as_ref().map_or(0, |strings| strings.from);
+        assert!(searched < 16, "searched {} bytes for the first two words", searched);
     }
 }
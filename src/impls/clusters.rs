@@ -0,0 +1,74 @@
+//! Extended grapheme clusters, so that what reads as one character is treated as one.
+//!
+//! A combining accent, the emoji after a zero width joiner, the second half of a flag: each is a
+//! `char` of its own, and read one at a time a rule can end a word between a letter and its accent
+//! or between two halves of a flag. Under `enhanced_accuracy` the walk reads clusters instead, each
+//! as the character it starts with, and the passes widen whatever a rule picks out to the clusters
+//! at its ends, so no word is cut inside one.
+
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::impls::passes::Located;
+
+/// Which of `chars` start a cluster, read as one text.
+///
+/// A character that is not where the one before it ended in the input starts one too: a cluster
+/// something was removed from inside is read as the pieces left of it.
+pub(crate) fn starts(chars: &[Located]) -> Vec<bool> {
+    let text: String = chars.iter().map(|&(_, c)| c).collect();
+    let mut bounds = text.grapheme_indices(true).map(|(at, _)| at).peekable();
+    let mut offset = 0;
+    let mut starts = Vec::with_capacity(chars.len());
+    for (idx, &(at, c)) in chars.iter().enumerate() {
+        let cut = idx > 0 && {
+            let (prev_at, prev) = chars[idx - 1];
+            prev_at + prev.len_utf8() != at
+        };
+        starts.push(bounds.next_if_eq(&offset).is_some() || cut);
+        offset += c.len_utf8();
+    }
+    starts
+}
+
+/// `chars` as the walk reads them: each cluster as its first character, with where it starts and
+/// where its last character ends in the input.
+pub(crate) fn units(chars: &[Located]) -> Vec<(usize, char, usize)> {
+    let starts = starts(chars);
+    let mut units: Vec<(usize, char, usize)> = Vec::with_capacity(chars.len());
+    for (&(at, c), starts) in chars.iter().zip(starts) {
+        match units.last_mut() {
+            Some(unit) if !starts => unit.2 = at + c.len_utf8(),
+            _ => units.push((at, c, at + c.len_utf8())),
+        }
+    }
+    units
+}
+
+/// `ranges`, ascending indices into `chars`, each widened to take in the whole of the clusters at
+/// its ends, and merged where that makes two overlap.
+///
+/// An empty range is a point between two characters, and is left where it is.
+pub(crate) fn widen(chars: &[Located], ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    if ranges.iter().all(Range::is_empty) {
+        return ranges;
+    }
+    let starts = starts(chars);
+    let mut widened: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        if range.is_empty() {
+            widened.push(range);
+            continue;
+        }
+        let start = (0..=range.start).rev().find(|&idx| starts[idx]).unwrap_or(0);
+        let end = (range.end..chars.len()).find(|&idx| starts[idx]).unwrap_or(chars.len());
+        match widened.last_mut() {
+            Some(last) if last.start != last.end && start < last.end => {
+                last.end = last.end.max(end);
+            },
+            _ => widened.push(start..end),
+        }
+    }
+    widened
+}
@@ -5,39 +5,115 @@
 //! every character. The list does not change while a string is being walked, so the answers are
 //! worked out once here and the walk reads flags instead.
 
-use crate::rules::RemoveMode::{All, Ends, Middle};
-use crate::rules::ResolverProcessingRule::{BoundEnd, BoundStart, Remove};
+use std::ops::Range;
+
+use aho_corasick::{AhoCorasick, MatchKind};
+
+use crate::categories::{self, GeneralCategory};
+use crate::rules::RemoveMode::{All, Appended, Ends, Middle, Prepended};
+use crate::rules::ResolverProcessingRule::{Attach, BoundEnd, BoundStart, Remove};
 use crate::rules::Scope::{FullInput, SingleWord};
-use crate::rules::{ResolverProcessingRule, RuleTarget};
+use crate::rules::{Direction, RemoveMode, ResolverProcessingRule, RuleSet, RuleTarget};
+use crate::segment::SegmentKind;
 
-/// Membership of a character in a set, as a pair of bitmaps over the ASCII range.
+/// Membership of a character in a set: a pair of bitmaps over the ASCII range, and beyond it, the
+/// general categories the set takes in and the characters it names either way.
 ///
-/// The sets in play are punctuation and the special characters, both of which are ASCII, so
-/// membership is a bit test rather than a scan of a string.
-#[derive(Default, Clone, Copy)]
-pub(crate) struct AsciiSet {
+/// Within ASCII, where the rules list their punctuation and special characters, membership is a bit
+/// test rather than a scan of a string. Beyond it, it is what the set says of the character itself,
+/// if anything, and otherwise a search of the category table.
+#[derive(Default, Clone)]
+pub(crate) struct CharSet {
     low: u64,
     high: u64,
+    /// The categories taken in beyond ASCII, a bit each.
+    categories: u16,
+    /// Characters beyond ASCII that are in the set, or out of it, whatever their category.
+    named: Vec<(char, bool)>,
 }
 
-impl AsciiSet {
+impl CharSet {
     pub(crate) fn from_chars(chars: &str) -> Self {
-        let mut set = AsciiSet::default();
+        let mut set = CharSet::default();
         for c in chars.chars() {
-            set.insert(c);
+            set.insert(c, true);
         }
         set
     }
 
+    /// The punctuation of `rules`.
+    ///
+    /// A character some rule names is read by that rule rather than by its category, unless the
+    /// punctuation lists it.
+    pub(crate) fn punct(rules: &RuleSet) -> Self {
+        let mut set = CharSet::from_chars(&rules.punct_chars_non_regex)
+            .with_categories(&rules.punct_categories);
+        for c in named_chars(&rules.resolution_pass_rules) {
+            if !c.is_ascii() && !set.named.iter().any(|(named, _)| *named == c) {
+                set.insert(c, false);
+            }
+        }
+        set
+    }
+
+    /// The special characters of `rules`.
+    pub(crate) fn special(rules: &RuleSet) -> Self {
+        CharSet::special_from(
+            &rules.non_punct_special_chars_non_regex(),
+            &rules.punct_chars_non_regex,
+            &rules.resolution_pass_rules,
+            &rules.punct_categories,
+            &rules.special_categories,
+        )
+    }
+
+    /// The special characters: `listed` within ASCII, and beyond it the special categories that are
+    /// not punctuation, less the characters `punct_chars` holds and those some rule names.
+    pub(crate) fn special_from(
+        listed: &str,
+        punct_chars: &str,
+        rules: &[ResolverProcessingRule],
+        punct_categories: &[GeneralCategory],
+        special_categories: &[GeneralCategory],
+    ) -> Self {
+        let categories: Vec<GeneralCategory> = special_categories
+            .iter()
+            .filter(|category| !punct_categories.contains(category))
+            .copied()
+            .collect();
+        let mut set = CharSet::from_chars(listed).with_categories(&categories);
+        for c in punct_chars.chars().filter(|c| !c.is_ascii()).chain(named_chars(rules)) {
+            set.insert(c, false);
+        }
+        set
+    }
+
+    pub(crate) fn with_categories(mut self, categories: &[GeneralCategory]) -> Self {
+        for category in categories {
+            self.categories |= category.bit();
+        }
+        self
+    }
+
     #[inline]
-    fn insert(&mut self, c: char) {
+    fn insert(&mut self, c: char, member: bool) {
         let value = c as u32;
-        if value < 64 {
-            self.low |= 1u64 << value;
-        } else if value < 128 {
-            self.high |= 1u64 << (value - 64);
+        let (bits, bit) = match value {
+            0..=63 => (&mut self.low, 1u64 << value),
+            64..=127 => (&mut self.high, 1u64 << (value - 64)),
+            _ => {
+                match self.named.iter_mut().find(|(named, _)| *named == c) {
+                    Some(named) => named.1 = member,
+                    None => self.named.push((c, member)),
+                }
+                return;
+            },
+        };
+        if member {
+            *bits |= bit;
+        } else {
+            *bits &= !bit;
         }
-        // outside ASCII nothing is recorded: neither set contains such a character
     }
 
     #[inline]
@@ -47,12 +123,45 @@ impl AsciiSet {
             self.low & (1u64 << value) != 0
         } else if value < 128 {
             self.high & (1u64 << (value - 64)) != 0
+        } else if let Some((_, member)) = self.named.iter().find(|(named, _)| *named == c) {
+            *member
         } else {
-            false
+            let of = GeneralCategory::of(c);
+            of.is_some_and(|category| self.categories & category.bit() != 0)
+        }
+    }
+
+    /// The set as the contents of a regex class, `ascii` being the contents that hold its members
+    /// within ASCII, as the rules spell them.
+    ///
+    /// The categories are the engines' own `\p{..}` classes, which are built from the same tables.
+    pub(crate) fn class(&self, ascii: &str) -> String {
+        let mut class = ascii.to_owned();
+        // beyond ASCII no character is one regex syntax gives a meaning to
+        class.extend(self.named.iter().filter(|(_, member)| *member).map(|(c, _)| c));
+        if self.categories != 0 {
+            class.push('[');
+            for category in GeneralCategory::PUNCTUATION.iter().chain(&GeneralCategory::SYMBOLS) {
+                if self.categories & category.bit() != 0 {
+                    class.push_str(&format!(r"\p{{{}}}", category.abbreviation()));
+                }
+            }
+            class.push_str(r"&&[^\x00-\x7F");
+            class.extend(self.named.iter().filter(|(_, member)| !*member).map(|(c, _)| c));
+            class.push_str("]]");
         }
+        class
     }
 }
 
+/// The characters `rules` name on their own.
+pub(crate) fn named_chars(rules: &[ResolverProcessingRule]) -> impl Iterator<Item = char> + '_ {
+    rules.iter().filter_map(|rule| match rule.target() {
+        Some(RuleTarget::Char(c)) => Some(*c),
+        _ => None,
+    })
+}
+
 /// What the ruleset says about one target.
 #[derive(Default, Clone, Copy)]
 pub(crate) struct TargetRules {
@@ -63,6 +172,7 @@ pub(crate) struct TargetRules {
     pub(crate) remove_ends_word: bool,
     pub(crate) bound_start: bool,
     pub(crate) bound_end: bool,
+    pub(crate) attach: Option<Direction>,
 }
 
 impl TargetRules {
@@ -76,10 +186,12 @@ impl TargetRules {
                     Middle(SingleWord) => this.remove_middle_word = true,
                     Ends(FullInput) => this.remove_ends_input = true,
                     Ends(SingleWord) => this.remove_ends_word = true,
-                    _ => (),
+                    // applied around the walk rather than in it: see `input_ends`, `within_word`
+                    Prepended(_) | Appended(_) | RemoveMode::None => (),
                 },
                 BoundStart(t) if t == target => this.bound_start = true,
                 BoundEnd(t) if t == target => this.bound_end = true,
+                Attach(t, direction) if t == target => this.attach = Some(*direction),
                 _ => (),
             }
         }
@@ -100,23 +212,57 @@ impl TargetRules {
 }
 
 /// The whole ruleset, in the form the walk reads.
-pub(crate) struct Compiled {
-    pub(crate) punct: AsciiSet,
-    pub(crate) non_punct_special: AsciiSet,
+///
+/// Public only so that it can be what `Charwalk` prepares for a
+/// [`Segmenter`](crate::segmenter::Segmenter); nothing outside the crate can name or build one.
+pub struct Compiled {
+    pub(crate) punct: CharSet,
+    pub(crate) non_punct_special: CharSet,
     pub(crate) punct_char: TargetRules,
     pub(crate) punct_run: TargetRules,
     pub(crate) numerics: TargetRules,
+    pub(crate) acronym: TargetRules,
+    pub(crate) acronym_min_len: usize,
     pub(crate) non_punct_special_rules: TargetRules,
     pub(crate) case_change: TargetRules,
     /// Rules naming one particular character, which are few and are checked in order.
     pub(crate) chars: Vec<(char, TargetRules)>,
+    /// Rules naming a string, in the order of the automaton's patterns.
+    pub(crate) strings: Vec<(String, TargetRules)>,
+    /// Finds every string at once, the longest where two start together; none without strings.
+    pub(crate) string_matcher: Option<AhoCorasick>,
+    /// Removals scoped to a single word, which cannot be decided until the word is complete and
+    /// so are applied to each word as it is finished rather than while walking it.
+    pub(crate) within_word: Vec<(RuleTarget, RemoveMode)>,
+    /// Removals of what leads or trails the whole input. Nothing resolution decides depends on
+    /// characters with nothing kept before or after them, so these are made before it starts.
+    pub(crate) input_ends: Vec<(RuleTarget, RemoveMode)>,
+    /// Targets glued onto a neighbouring word, which is decided once the neighbours are known.
+    pub(crate) attach: Vec<(RuleTarget, Direction)>,
+    /// The rules of the passes either side of resolution, which read them as they are.
+    pub(crate) pre_pass: Vec<ResolverProcessingRule>,
+    pub(crate) post_pass: Vec<ResolverProcessingRule>,
 }
 
 impl Compiled {
+    /// `rules`, with its punctuation and special characters as charwalk reads them.
+    pub(crate) fn of(rules: &RuleSet) -> Self {
+        Compiled {
+            pre_pass: rules.pre_pass_rules.clone(),
+            post_pass: rules.post_pass_rules.clone(),
+            acronym_min_len: rules.acronym_min_len,
+            ..Compiled::new(
+                &rules.resolution_pass_rules,
+                CharSet::punct(rules),
+                CharSet::special(rules),
+            )
+        }
+    }
+
     pub(crate) fn new(
         rules: &[ResolverProcessingRule],
-        punct_chars: &str,
-        non_punct_special_chars: &str,
+        punct: CharSet,
+        non_punct_special: CharSet,
     ) -> Self {
         let mut chars = Vec::new();
         for rule in rules {
@@ -127,15 +273,197 @@ impl Compiled {
             }
         }
 
+        let mut strings: Vec<(String, TargetRules)> = Vec::new();
+        for rule in rules {
+            if let Some(RuleTarget::String(string)) = rule.target() {
+                if !string.is_empty() && !strings.iter().any(|(seen, _)| seen == string) {
+                    let target = RuleTarget::String(string.clone());
+                    let mut string_rules = TargetRules::of(rules, &target);
+                    // split out whole, so that the attachment finds it as a word of its own
+                    if string_rules.attach.is_some() {
+                        string_rules.bound_start = true;
+                        string_rules.bound_end = true;
+                    }
+                    strings.push((string.clone(), string_rules));
+                }
+            }
+        }
+        let string_matcher = (!strings.is_empty()).then(|| {
+            AhoCorasick::builder()
+                .match_kind(MatchKind::LeftmostLongest)
+                .build(strings.iter().map(|(string, _)| string))
+                .expect("string targets are few and short enough to build a matcher for")
+        });
+
+        let within_word = rules
+            .iter()
+            .filter_map(|rule| match rule {
+                Remove(target, mode) if mode.scope() == Some(&SingleWord) => {
+                    Some((target.clone(), mode.clone()))
+                },
+                _ => None,
+            })
+            .collect();
+        let input_ends = rules
+            .iter()
+            .filter_map(|rule| match rule {
+                Remove(target, mode @ (Prepended(FullInput) | Appended(FullInput))) => {
+                    Some((target.clone(), mode.clone()))
+                },
+                _ => None,
+            })
+            .collect();
+        let attach = rules
+            .iter()
+            .filter_map(|rule| match rule {
+                Attach(target, direction) => Some((target.clone(), *direction)),
+                _ => None,
+            })
+            .collect();
+
         Compiled {
-            punct: AsciiSet::from_chars(punct_chars),
-            non_punct_special: AsciiSet::from_chars(non_punct_special_chars),
+            punct,
+            non_punct_special,
             punct_char: TargetRules::of(rules, &RuleTarget::PunctSpecialChar),
             punct_run: TargetRules::of(rules, &RuleTarget::PunctSpecialCharRun),
             numerics: TargetRules::of(rules, &RuleTarget::Numerics),
+            acronym: TargetRules::of(rules, &RuleTarget::Acronym),
+            // the default's, as `ResolverRules::acronym_min_len` has it
+            acronym_min_len: 2,
             non_punct_special_rules: TargetRules::of(rules, &RuleTarget::NonPunctSpecialChar),
             case_change: TargetRules::of(rules, &RuleTarget::CaseChangeNonAcronym),
             chars,
+            strings,
+            string_matcher,
+            within_word,
+            input_ends,
+            attach,
+            pre_pass: Vec::new(),
+            post_pass: Vec::new(),
+        }
+    }
+}
+
+impl Compiled {
+    /// The rules naming `c` itself, if any do.
+    #[inline]
+    pub(crate) fn char_rules(&self, c: char) -> Option<&TargetRules> {
+        self.chars.iter().find(|(inner_c, _)| *inner_c == c).map(|(_, rules)| rules)
+    }
+
+    /// Whether `c` is upper case, ASCII answered without the tables.
+    ///
+    /// The same in every locale: the letters a [`Locale`](crate::locale::Locale) cases its own way
+    /// are upper or lower case in it as they are anywhere else.
+    #[inline]
+    pub(crate) fn is_upper(&self, c: char) -> bool {
+        if c.is_ascii() {
+            c.is_ascii_uppercase()
+        } else {
+            c.is_uppercase()
+        }
+    }
+
+    /// Whether `c` is lower case, ASCII answered without the tables.
+    #[inline]
+    pub(crate) fn is_lower(&self, c: char) -> bool {
+        if c.is_ascii() {
+            c.is_ascii_lowercase()
+        } else {
+            c.is_lowercase()
+        }
+    }
+
+    /// Whether a case change to `c` starts a word: a capital, or a titlecase letter such as `ǅ`,
+    /// which is the capital of a digraph at the start of a word written in lower case.
+    #[inline]
+    pub(crate) fn starts_word(&self, c: char) -> bool {
+        self.is_upper(c) || categories::is_titlecase(c)
+    }
+
+    /// How many characters the acronym `chars` starts with runs to, if it starts with one.
+    ///
+    /// That is its capitals, less the last where a lower case letter follows, and with a plural
+    /// `s` where one follows on its own.
+    pub(crate) fn acronym_at(&self, mut chars: impl Iterator<Item = char>) -> Option<usize> {
+        let mut capitals = 0;
+        let mut after = None;
+        for c in chars.by_ref() {
+            if !self.is_upper(c) {
+                after = Some(c);
+                break;
+            }
+            capitals += 1;
+        }
+        let len = match after {
+            Some('s')
+                if capitals >= self.acronym_min_len
+                    && !chars.next().is_some_and(|next| self.is_lower(next)) =>
+            {
+                capitals + 1
+            },
+            Some(c) if self.is_lower(c) => capitals.saturating_sub(1),
+            _ => capitals,
+        };
+        (len > 0 && len >= self.acronym_min_len).then_some(len)
+    }
+
+    /// The acronyms in `chars`, as ranges of indices into it.
+    pub(crate) fn acronyms(&self, chars: &[char]) -> Vec<Range<usize>> {
+        let mut found = Vec::new();
+        let mut idx = 0;
+        while idx < chars.len() {
+            let starts = self.is_upper(chars[idx]) && (idx == 0 || !self.is_upper(chars[idx - 1]));
+            let len = starts.then(|| self.acronym_at(chars[idx..].iter().copied())).flatten();
+            if let Some(len) = len {
+                found.push(idx..idx + len);
+                idx += len;
+            } else {
+                idx += 1;
+            }
+        }
+        found
+    }
+
+    /// Which target a finished word answers to, read off its text.
+    ///
+    /// Checked from the most specific down: a character rule at either end says more about the
+    /// word than what the rest of it is made of.
+    pub(crate) fn kind_of(&self, word: &str) -> SegmentKind {
+        let mut chars = word.chars();
+        let (Some(first), last) = (chars.next(), chars.next_back()) else {
+            return SegmentKind::Word;
+        };
+        if let Some(last) = last {
+            let leads = |rules: &TargetRules| {
+                rules.bound_start || matches!(rules.attach, Some(Direction::Next | Direction::Auto))
+            };
+            let trails = |rules: &TargetRules| {
+                rules.bound_end
+                    || matches!(rules.attach, Some(Direction::Previous | Direction::Auto))
+            };
+            if self.char_rules(first).is_some_and(leads) {
+                return SegmentKind::Prefixed(first);
+            }
+            if self.char_rules(last).is_some_and(trails) {
+                return SegmentKind::Suffixed(last);
+            }
+        }
+        if word.chars().all(|c| self.punct.contains(c)) {
+            if last.is_some() && !self.punct_run.is_inert() && word.chars().all(|c| c == first) {
+                return SegmentKind::PunctRun;
+            }
+            return SegmentKind::Punct;
+        }
+        if !self.numerics.is_inert() && word.chars().all(|c| c.is_numeric()) {
+            return SegmentKind::Numeric;
+        }
+        if !word.chars().any(char::is_alphanumeric) {
+            return SegmentKind::Special;
+        }
+        if !self.acronym.is_inert() && self.acronym_at(word.chars()) == Some(word.chars().count()) {
+            return SegmentKind::Acronym;
         }
+        SegmentKind::Word
     }
 }
@@ -1,17 +1,37 @@
+use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use fancy_regex::Regex as RE;
 
+use crate::capabilities::{removes_in_resolution, Capabilities, TargetKind};
+use crate::error::SegmentationError;
 use crate::rules::{
-    DefaultRules, RemoveMode, ResolverProcessingRule, ResolverRules, RuleTarget, Scope,
+    DefaultRules, RemoveMode, ResolverProcessingRule, ResolverRules, RuleSet, RuleTarget, Scope,
 };
+use crate::impls::compiled::{CharSet, Compiled, TargetRules};
+use crate::impls::passes;
+use crate::segment::{place, CharCursor, Segment};
 use crate::{CompiledRules, WordBoundResolverImpl};
 use crate::{
-    __str_ext__cache_static_regex, __str_ext__init_capture_iter, __str_ext__instance_words_vec,
+    __str_ext__cache_static_regex, __str_ext__compile_regex, __str_ext__init_regex,
+    __str_ext__instance_words_vec, __str_ext__try_compile_regex, __str_ext__try_init_regex,
 };
 
 __str_ext__cache_static_regex!(RE, FancyRegex::<R>);
 
+/// The name this backend goes by in a [`SegmentationError`].
+const BACKEND: &str = "fancy_regex";
+
+/// The targets resolution removes, with a class or a pattern of their own.
+const REMOVES: &[TargetKind] = &[
+    TargetKind::String,
+    TargetKind::Char,
+    TargetKind::Acronym,
+    TargetKind::PunctSpecialChar,
+    TargetKind::PunctSpecialCharRun,
+];
+
 pub struct FancyRegex<R: ResolverRules = DefaultRules> {
     _phantom_data: PhantomData<R>,
 }
@@ -20,42 +40,99 @@ impl<R: ResolverRules> WordBoundResolverImpl<R> for FancyRegex<R>
 where
     R: 'static,
 {
-    fn resolver(s: &str) -> Vec<String> {
-        __str_ext__instance_words_vec!(s, words);
-        __str_ext__init_capture_iter!(fancy re, RE, FancyRegex::<R>, captures_iter, s);
-        let mut last = 0;
-        for match_ in captures_iter {
-            let cap = match_.expect("Unable to find capture");
-            let start = cap.start();
-            let end = cap.end();
-
-            if start > last {
-                let part = &s[last..start];
-                words.push(part.to_lowercase());
-            }
+    type Iter<'a> = std::vec::IntoIter<Segment<'a>>;
 
-            last = end;
-        }
+    fn iter(s: &str) -> Self::Iter<'_> {
+        let rules = RuleSet::of::<R>();
+        supported(&rules).unwrap_or_else(|error| panic!("{}", error));
+        __str_ext__init_regex!(re, RE, FancyRegex::<R>);
+        let segments = Self::segments_by(s, re, &Compiled::of(&rules));
+        segments.unwrap_or_else(|error| panic!("{}", error)).into_iter()
+    }
 
-        if last < s.len() {
-            words.push(s[(last)..].to_lowercase());
-        }
+    fn iter_with_rules<'a>(s: &'a str, rules: &RuleSet) -> Self::Iter<'a> {
+        supported(rules).unwrap_or_else(|error| panic!("{}", error));
+        __str_ext__compile_regex!(re, RE, FancyRegex::<R>, rules);
+        let segments = Self::segments_by(s, re, &Compiled::of(rules));
+        segments.unwrap_or_else(|error| panic!("{}", error)).into_iter()
+    }
+
+    /// The pattern compiled from the ruleset, and the rest of it as the passes and the segment
+    /// kinds read it.
+    type Prepared = (RE, Compiled);
 
-        words
+    fn prepare(rules: &RuleSet) -> Self::Prepared {
+        Self::try_prepare(rules).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn iter_prepared<'a>(prepared: &Self::Prepared, s: &'a str) -> Self::Iter<'a> {
+        Self::try_iter_prepared(prepared, s).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn try_prepare(rules: &RuleSet) -> Result<Self::Prepared, SegmentationError> {
+        supported(rules)?;
+        let re = __str_ext__try_compile_regex!(RE, FancyRegex::<R>, rules)?;
+        Ok((re, Compiled::of(rules)))
+    }
+
+    /// Fails where the pattern does, which for a backtracking one can be on the input alone.
+    fn try_iter_prepared<'a>(
+        (re, compiled): &Self::Prepared,
+        s: &'a str,
+    ) -> Result<Self::Iter<'a>, SegmentationError> {
+        Ok(Self::segments_by(s, re, compiled)?.into_iter())
+    }
+
+    /// Through the pattern shared by `R`, as [`resolver`](Self::resolver) is.
+    fn try_resolver(s: &str) -> Result<Vec<String>, SegmentationError> {
+        let rules = RuleSet::of::<R>();
+        supported(&rules)?;
+        __str_ext__try_init_regex!(re, RE, FancyRegex::<R>);
+        let (case, locale) = (rules.case_mode, rules.locale);
+        let segments = Self::segments_by(s, re, &Compiled::of(&rules))?;
+        Ok(segments.iter().map(|segment| case.apply_in(&segment.text, locale)).collect())
+    }
+
+    /// What the regex backend reads, but for a case change a word ends at, which the pattern for
+    /// the one it starts at already bounds.
+    fn capabilities() -> Capabilities {
+        Capabilities {
+            backend: BACKEND,
+            lazy: false,
+            removes: REMOVES,
+            remove_modes: &[
+                TargetKind::String,
+                TargetKind::PunctSpecialChar,
+                TargetKind::PunctSpecialCharRun,
+            ],
+            refuses: true,
+            bound_starts: TargetKind::WITHIN_WORDS,
+            bound_ends: &[
+                TargetKind::String,
+                TargetKind::Char,
+                TargetKind::Numerics,
+                TargetKind::Acronym,
+                TargetKind::PunctSpecialChar,
+                TargetKind::PunctSpecialCharRun,
+                TargetKind::NonPunctSpecialChar,
+            ],
+            one_sided_runs: false,
+            whole_strings: true,
+        }
     }
 
-    fn compile_rules() -> CompiledRules {
+    fn compile_rule_set(rules: &RuleSet) -> CompiledRules {
         let mut pattern: Vec<Box<str>> = vec![];
+        let mut ends: Vec<Box<str>> = vec![];
 
-        let mut flag_case_change = false;
         let mut flag_punct = false;
         let mut remove_puncts_all = false;
         let mut remove_puncts_ends = false;
         let mut remove_puncts_mids = false;
 
         // Get punctuation characters from rules
-        let punct_chars = R::punct_chars();
-        let non_punct_special_chars = R::non_punct_special_chars();
+        let punct_chars = &CharSet::punct(rules).class(&rules.punct_chars);
+        let non_punct_special_chars = rules.non_punct_special_chars();
 
         // // Ensure punctuations are properly escaped for regex
         // let punct_chars = regex::escape(&punct_chars);
@@ -91,103 +168,168 @@ where
         // `(?=\W|$)` is a positive lookahead to ensure that punctuation is followed by a non-word character or end of the string.
         let punct_end_pattern = format!(r"(?<=\w)[{}](?=\W|$)", punct_chars);
 
-        let mut punct_char_pattern_merge = String::from("");
+        // a run is the same punctuation character again, all of it, which takes a backreference,
+        // and each run pattern names its own group so that the backreferences stay apart once
+        // joined. The one named `run` is kept as a word where it is matched rather than removed
+        let run = |name: &str| format!(r"(?<{name}>[{punct_chars}])\k<{name}>+(?!\k<{name}>)");
+        // where the run itself does not start
+        let run_start = |name: &str| format!(r"(?<=(?<{name}>.))(?!\k<{name}>)");
+        // a punctuation character that starts no run
+        let lone = |name: &str| {
+            format!(r"(?:(?!(?<{name}>[{punct_chars}])\k<{name}>)[{punct_chars}])")
+        };
+        let bound_runs = rules.resolution_pass_rules.iter().any(|rule| {
+            matches!(
+                rule,
+                ResolverProcessingRule::BoundStart(RuleTarget::PunctSpecialCharRun)
+                    | ResolverProcessingRule::BoundEnd(RuleTarget::PunctSpecialCharRun)
+            )
+        });
+
+        // an acronym as the `Acronym` target reads one: its capitals, less the last where a lower
+        // case letter follows, and with a lone plural `s`
+        let min = rules.acronym_min_len.max(1);
+        let acronym = format!(
+            r"(?<!\p{{Lu}})(?:\p{{Lu}}{{{min},}}s(?!\p{{Ll}})|\p{{Lu}}{{{min},}}(?!\p{{Ll}}))"
+        );
+        let acronym_start =
+            format!(r"(?<=[^\p{{Lu}}])(?=\p{{Lu}}{{{min}}}(?:s(?!\p{{Ll}})|[^\p{{Ll}}]|$))");
+        // the capitals before one followed by lower case, unless that one ends a plural acronym,
+        // and after an acronym followed by a capital or a digit: what else can follow one is
+        // punctuation or a special character, which bound it themselves
+        let acronym_end = format!(
+            concat!(
+                r"(?<=\p{{Lu}})(?=\p{{Lu}}\p{{Ll}})(?!(?<=\p{{Lu}}{{{}}})\p{{Lu}}s(?!\p{{Ll}}))",
+                r"|(?<=\p{{Lu}}{{{min}}}s)(?=[\p{{Lu}}\d])|(?<=\p{{Lu}}{{{min}}})(?=\d)"
+            ),
+            (min - 1).max(1),
+            min = min
+        );
 
-        for rule in R::resolution_pass_rules() {
+        for rule in rules.resolution_pass_rules.iter().cloned() {
             match rule {
+                // removals within a word are not boundaries: they are applied to each word once
+                // the input has been split, and of what leads or trails the input before that
+                ResolverProcessingRule::Remove(_, mode) if !removes_in_resolution(&mode) => {},
                 ResolverProcessingRule::Remove(target, mode) => match target {
                     RuleTarget::Char(c) => {
                         pattern.push(format!(r"[{}]", c).into());
                     },
+                    // found apart from the pattern, by `split`
+                    RuleTarget::String(_) => {},
+                    RuleTarget::Acronym => {
+                        pattern.push(acronym.as_str().into());
+                    },
                     RuleTarget::PunctSpecialChar => match mode {
                         RemoveMode::All => {
                             remove_puncts_all = true;
                         },
-                        RemoveMode::Middle(scope) => match scope {
-                            Scope::FullInput => {
-                                remove_puncts_mids = true;
-                            },
-                            Scope::SingleWord => {
-                                unimplemented!()
-                            },
+                        RemoveMode::Middle(Scope::FullInput) => {
+                            remove_puncts_mids = true;
                         },
-                        RemoveMode::Ends(scope) => match scope {
-                            Scope::FullInput => {
-                                remove_puncts_ends = true;
-                            },
-                            Scope::SingleWord => {
-                                unimplemented!()
-                            },
-                        },
-                        _ => {
-                            unimplemented!()
+                        RemoveMode::Ends(Scope::FullInput) => {
+                            remove_puncts_ends = true;
                         },
+                        _ => unreachable!("skipped above"),
                     },
-                    _ => {
-                        unimplemented!()
+                    RuleTarget::PunctSpecialCharRun => match mode {
+                        RemoveMode::All => {
+                            pattern.push(run("run_all").into());
+                        },
+                        RemoveMode::Middle(Scope::FullInput) => {
+                            let middle = run("run_middle");
+                            let start = run_start("run_middle_before");
+                            pattern.push(format!(r"{}{}(?!$)", start, middle).into());
+                        },
+                        RemoveMode::Ends(Scope::FullInput) => {
+                            let (start, end) = (run("run_start"), run("run_end"));
+                            pattern.push(format!(r"^{}|{}$", start, end).into());
+                        },
+                        _ => unreachable!("skipped above"),
                     },
+                    _ => unreachable!("rejected by `supported`"),
                 },
                 _ => {},
             }
         }
-        for rule in R::resolution_pass_rules() {
+        // ahead of the punctuation patterns, which would otherwise remove a run as punctuation,
+        // and with the punctuation either side of it where that is removed from the middle
+        if bound_runs && remove_puncts_mids {
+            let (before, after) = (lone("run_lone_before"), lone("run_lone_after"));
+            let run = run("run_char");
+            let flanked = format!(r"(?:(?<=\w){}+)?(?<run>{})(?:{}+(?=\w))?", before, run, after);
+            pattern.push(flanked.into());
+        } else if bound_runs {
+            pattern.push(format!(r"(?<run>{})", run("run_char")).into());
+        }
+        // one pattern for punctuation, bounded on both sides at once, so pushed for whichever of
+        // its two rules comes first
+        let punct_char_pattern: Box<str> = if remove_puncts_all {
+            punct_char_pattern_exclude.into()
+        } else {
+            let mut punct_pattern_parts = vec![];
+            if remove_puncts_ends {
+                punct_pattern_parts.push(punct_start_pattern.to_owned());
+                punct_pattern_parts.push(punct_middle_pattern.to_owned());
+                punct_pattern_parts.push(punct_end_pattern.to_owned());
+            }
+            if remove_puncts_mids {
+                // punct_pattern_parts.push(punct_start_pattern_include.to_owned());
+                punct_pattern_parts.push(punct_middle_pattern.to_owned());
+                // punct_pattern_parts.push(punct_end_pattern_include.to_owned());
+            }
+            punct_pattern_parts.join("|").into()
+        };
+        for rule in rules.resolution_pass_rules.iter().cloned() {
             match rule {
                 ResolverProcessingRule::BoundStart(target) => match target {
                     RuleTarget::Char(c) => {
                         pattern.push(format!(r"(?={})", c)
                             .into());
                     }
+                    // found apart from the pattern, by `split`
+                    RuleTarget::String(_) => {}
                     RuleTarget::CaseChangeNonAcronym => /*(dir) => match dir */{
                         // Direction::Next => {
-                        pattern.push(r"(?=\p{Lu})\p{Ll}|(?<=\p{Ll})(?=\p{Lu})".into());
-                        flag_case_change = true;
+                        // a titlecase letter starts a word after lower case, as a capital
+                        // does, and after a capital where lower case follows it
+                        pattern.push(
+                            concat!(
+                                r"(?=\p{Lu})\p{Ll}|(?<=\p{Ll})(?=[\p{Lu}\p{Lt}])",
+                                r"|(?<=\p{Lu})(?=\p{Lt}\p{Ll})"
+                            )
+                            .into(),
+                        );
                         // }
                         // Direction::Previous => {
                         //     pattern.push(r"(?=\p{Ll})\p{Lu}".into());
-                        //     flag_case_change = true;
                         // }
                         // _ => { unimplemented!() }
                     }
                     RuleTarget::Acronym => {
-                        unimplemented!()
+                        pattern.push(acronym_start.as_str().into());
                     }
-                    RuleTarget::PunctSpecialChar => {
-                        if !flag_punct {
-                            if remove_puncts_all {
-                                pattern.push(punct_char_pattern_exclude.into());
-                            } else {
-                                let mut punct_pattern_parts = vec![];
-                                if remove_puncts_ends {
-                                    punct_pattern_parts.push(punct_start_pattern.to_owned());
-                                    punct_pattern_parts.push(punct_middle_pattern.to_owned());
-                                    punct_pattern_parts.push(punct_end_pattern.to_owned());
-                                }
-                                if remove_puncts_mids {
-                                    // punct_pattern_parts.push(punct_start_pattern_include.to_owned());
-                                    punct_pattern_parts.push(punct_middle_pattern.to_owned());
-                                    // punct_pattern_parts.push(punct_end_pattern_include.to_owned());
-                                }
-                                punct_char_pattern_merge = punct_pattern_parts.join("|");
-                                pattern.push(punct_char_pattern_merge.into());
-                            }
-                            flag_punct = true;
-                        }
+                    RuleTarget::PunctSpecialChar if !flag_punct => {
+                        pattern.push(punct_char_pattern.clone());
+                        flag_punct = true;
                     }
+                    RuleTarget::PunctSpecialChar => {}
                     RuleTarget::Numerics => {
                         pattern.push(r"(?<=\D)(?=\d)".into()); // a boundary at the start of numerics
                     }
                     RuleTarget::NonPunctSpecialChar => {
-                        pattern.push(format!(r"(?={})", non_punct_special_chars).into());
+                        // never before a mark, which belongs with the character it follows
+                        pattern.push(format!(r"(?={})(?!\p{{M}})", non_punct_special_chars).into());
                     }
                     RuleTarget::PunctSpecialCharRun => {
-                        // not expressed in the pattern: this backend does not implement
-                        // punctuation runs, charwalk does. Named so rule compilation stays total.
+                        // NOTE: already matched ahead of the bounds, as a word of its own
                     }
-                    _ => { unimplemented!() }
+                    // read by the passes, once there are words
+                    RuleTarget::Word(_) => {}
                 },
                 ResolverProcessingRule::BoundEnd(target) => match target {
                     RuleTarget::Char(c) => {
-                        pattern.push(format!(r"(?<={})", c).into());
+                        ends.push(format!(r"(?<={})", c).into());
                     }
                     RuleTarget::CaseChangeNonAcronym/*(dir) => match dir */ => {
                         // Direction::Next => {
@@ -199,41 +341,34 @@ where
                         // _ => { unimplemented!() }
                     }
                     RuleTarget::Acronym => {
-                        pattern.push(r"(?<=\p{Lu})(?=\p{Lu}\p{Ll})".into());
+                        ends.push(acronym_end.as_str().into());
                     }
-                    RuleTarget::PunctSpecialChar => {
-                        if !flag_punct {
-                            if remove_puncts_all {
-                                pattern.push(punct_char_pattern_exclude.into());
-                            } else {
-                                let mut punct_pattern_parts = vec![];
-                                if remove_puncts_ends {
-                                    punct_pattern_parts.push(punct_start_pattern.to_owned());
-                                    punct_pattern_parts.push(punct_middle_pattern.to_owned());
-                                    punct_pattern_parts.push(punct_end_pattern.to_owned());
-                                }
-                                if remove_puncts_mids {
-                                    // punct_pattern_parts.push(punct_start_pattern_include.to_owned());
-                                    punct_pattern_parts.push(punct_middle_pattern.to_owned());
-                                    // punct_pattern_parts.push(punct_end_pattern_include.to_owned());
-                                }
-                                punct_char_pattern_merge = punct_pattern_parts.join("|");
-                                pattern.push(punct_char_pattern_merge.into());
-                            }
-                            flag_punct = true;
-                        }
+                    RuleTarget::PunctSpecialChar if !flag_punct => {
+                        pattern.push(punct_char_pattern.clone());
+                        flag_punct = true;
                     }
                     RuleTarget::NonPunctSpecialChar => {
-                        pattern.push(format!(r"(?<={})(?=.)", non_punct_special_chars).into());
+                        let end = format!(r"(?<={})(?=\P{{M}})", non_punct_special_chars);
+                        ends.push(end.into());
                     }
                     RuleTarget::Numerics => {
-                        pattern.push(r"(?<=\d)(?=\D)".into()); // a boundary at the end of numerics
+                        ends.push(r"(?<=\d)(?=\D)".into()); // a boundary at the end of numerics
                     }
                     _ => {}
                 },
                 _ => {},
             }
         }
+        // the ends, last, so that where a word also starts it is the start that is matched, and
+        // named, so that `split` tells the end of a string from what follows one
+        if !ends.is_empty() {
+            pattern.push(format!(r"(?<end>{})", ends.join("|")).into());
+        }
+
+        // a pattern that never matches, where there is nothing but strings to bound at
+        if pattern.is_empty() {
+            pattern.push(r"[^\s\S]".into());
+        }
 
         // Join all the regex strings with |
         let compiled_pattern = pattern.join("|");
@@ -243,6 +378,194 @@ where
     }
 }
 
+impl<R: ResolverRules> FancyRegex<R>
+where
+    R: 'static,
+{
+    /// The input between the pattern's matches, which are the boundaries and what they remove.
+    ///
+    /// What the pre-pass leaves of the input is a copy of it, so the words split from that are
+    /// placed back in the input to be borrowed from it.
+    fn segments_by<'a>(
+        s: &'a str,
+        re: &RE,
+        compiled: &Compiled,
+    ) -> Result<Vec<Segment<'a>>, SegmentationError> {
+        let segments = match passes::pre_pass(compiled, s) {
+            Some(kept) => {
+                let kept: String = kept.into_iter().map(|(_, c)| c).collect();
+                let mut chars = CharCursor::new(s);
+                let mut from = 0;
+                Self::split(&kept, re, compiled)?
+                    .into_iter()
+                    .map(|segment| {
+                        let (word, range) = place(s, from, segment.text.into_owned());
+                        from = range.end;
+                        chars.segment(word, range, segment.kind)
+                    })
+                    .collect()
+            },
+            None => Self::split(s, re, compiled)?,
+        };
+        let segments = if compiled.within_word.is_empty() {
+            segments
+        } else {
+            segments
+                .into_iter()
+                .filter_map(|segment| passes::within_word(compiled, s, segment))
+                .collect()
+        };
+        Ok(passes::post_pass(compiled, s, segments))
+    }
+
+    /// Strings are found apart from the pattern, which does not bound or remove anything inside
+    /// one, and are then bounded or removed as their own rules say.
+    fn split<'a>(
+        s: &'a str,
+        re: &RE,
+        compiled: &Compiled,
+    ) -> Result<Vec<Segment<'a>>, SegmentationError> {
+        __str_ext__instance_words_vec!(s, words);
+        let mut chars = CharCursor::new(s);
+        let strings: Vec<(Range<usize>, TargetRules)> = match &compiled.string_matcher {
+            Some(matcher) => matcher
+                .find_iter(s)
+                .map(|found| (found.range(), compiled.strings[found.pattern().as_usize()].1))
+                .collect(),
+            None => Vec::new(),
+        };
+        let mut last = 0;
+        // Since split function is not available in fancy_regex
+        // we do it manually using captures_iter, which also tells a run kept as a word apart
+        for captures in re.captures_iter(s) {
+            let captures = captures.map_err(|error| SegmentationError::MatchFailed {
+                backend: BACKEND,
+                reason: error.to_string(),
+            })?;
+            let cap = captures.get(0).expect("a match is its own first group");
+            // a string's last character ends nothing, only its rules do
+            if cap.range().is_empty()
+                && captures.name("end").is_some()
+                && strings.iter().any(|(string, _)| string.end == cap.start())
+            {
+                continue;
+            }
+            let parts = outside_strings(cap.range(), &strings);
+            let whole = parts.len() == 1 && parts[0] == cap.range();
+            for part in parts {
+                if part.start > last {
+                    let range = last..part.start;
+                    Self::push_word(&mut words, &mut chars, s, range, &strings, compiled);
+                }
+                last = part.end;
+            }
+            if let Some(run) = captures.name("run").filter(|_| whole) {
+                let word = run.as_str();
+                let range = run.range();
+                words.push(chars.segment(Cow::Borrowed(word), range, compiled.kind_of(word)));
+            }
+        }
+
+        if last < s.len() {
+            Self::push_word(&mut words, &mut chars, s, last..s.len(), &strings, compiled);
+        }
+
+        Ok(words)
+    }
+
+    /// Pushes the word at `range` of `s`, bounded where a string in it bounds and without the
+    /// strings removed from it.
+    fn push_word<'a>(
+        words: &mut Vec<Segment<'a>>,
+        chars: &mut CharCursor<'a>,
+        s: &'a str,
+        range: Range<usize>,
+        strings: &[(Range<usize>, TargetRules)],
+        compiled: &Compiled,
+    ) {
+        // the parts of the word so far, which are more than one where a string was removed
+        let mut parts: Vec<Range<usize>> = Vec::new();
+        let mut flush = |parts: &mut Vec<Range<usize>>| {
+            parts.retain(|part| !part.is_empty());
+            let (Some(first), Some(end)) = (parts.first(), parts.last()) else {
+                return;
+            };
+            let range = first.start..end.end;
+            let word = match parts.as_slice() {
+                [part] => Cow::Borrowed(&s[part.clone()]),
+                _ => Cow::Owned(parts.iter().map(|part| &s[part.clone()]).collect()),
+            };
+            let kind = compiled.kind_of(&word);
+            words.push(chars.segment(word, range, kind));
+            parts.clear();
+        };
+        let mut from = range.start;
+        let within = strings.iter().filter(|(string, _)| {
+            range.start <= string.start && string.end <= range.end
+        });
+        for (string, rules) in within {
+            let ends = string.start == 0 || string.end == s.len();
+            let removed = rules.remove_all
+                || (rules.remove_ends_input && ends)
+                || (rules.remove_middle_input && !ends);
+            if rules.bound_start {
+                parts.push(from..string.start);
+                flush(&mut parts);
+                from = string.start;
+            }
+            if removed {
+                parts.push(from..string.start);
+                from = string.end;
+            }
+            if rules.bound_end {
+                parts.push(from..string.end);
+                flush(&mut parts);
+                from = string.end;
+            }
+        }
+        parts.push(from..range.end);
+        flush(&mut parts);
+    }
+}
+
+/// The parts of a match at `range` outside every string of `strings`, which are in order and
+/// apart. A boundary inside a string, or where one starts, is no boundary: what is there answers
+/// to the string's rules alone.
+fn outside_strings(
+    range: Range<usize>,
+    strings: &[(Range<usize>, TargetRules)],
+) -> Vec<Range<usize>> {
+    let mut from = range.start;
+    let mut parts = Vec::new();
+    for (string, _) in strings {
+        if string.end <= from {
+            continue;
+        }
+        if range.is_empty() {
+            if string.start <= from {
+                return parts;
+            }
+            break;
+        }
+        if string.start >= range.end {
+            break;
+        }
+        if string.start > from {
+            parts.push(from..string.start);
+        }
+        from = string.end;
+    }
+    if from < range.end || (range.is_empty() && from == range.start) {
+        parts.push(from..range.end);
+    }
+    parts
+}
+
+/// `Ok` unless this backend refuses a rule of `rules`.
+fn supported(rules: &RuleSet) -> Result<(), SegmentationError> {
+    FancyRegex::<DefaultRules>::capabilities().supported(rules)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::rules::DefaultRules;
@@ -269,4 +592,32 @@ mod tests {
             "Compiled pattern does not match expectations"
         );
     }
+
+    /// The default rules, under a type of their own so that nothing else fills their pattern in.
+    #[cfg(not(feature = "optimize_for_memory"))]
+    struct Untouched;
+
+    #[cfg(not(feature = "optimize_for_memory"))]
+    impl ResolverRules for Untouched {
+        fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+            DefaultRules::resolution_pass_rules()
+        }
+    }
+
+    /// The pattern `try_resolve` reads is the shared one: put one matching nothing there, and
+    /// every call after resolves by it rather than compiling the rules again.
+    #[test]
+    #[cfg(not(feature = "optimize_for_memory"))]
+    fn try_resolve_reuses_the_shared_pattern() {
+        let key = std::any::TypeId::of::<Untouched>();
+        let shared = std::sync::Arc::new(RE::new(r"[^\s\S]").unwrap());
+        REGEXES.get_or_init(Default::default).write().unwrap().insert(key, shared.clone());
+        for _ in 0..2 {
+            // a pattern matching nothing splits nowhere
+            let words = FancyRegex::<Untouched>::try_resolver("fooBar");
+            assert_eq!(words, Ok(vec!["foobar".to_string()]));
+        }
+        let cached = REGEXES.get().unwrap().read().unwrap()[&key].clone();
+        assert!(std::sync::Arc::ptr_eq(&cached, &shared));
+    }
 }
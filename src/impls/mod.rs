@@ -1,9 +1,14 @@
 pub(crate) mod compiled;
 pub mod charwalk;
+#[cfg(feature = "enhanced_accuracy")]
+pub(crate) mod clusters;
+pub(crate) mod passes;
 #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
 pub mod fancy_regex;
 #[cfg(any(feature = "use_regex", feature = "benchmark"))]
 pub mod regex;
+#[cfg(feature = "enhanced_accuracy")]
+pub mod unicode;
 
 #[macro_export]
 macro_rules! __str_ext__instance_words_vec {
@@ -17,7 +22,7 @@ macro_rules! __str_ext__instance_words_vec {
     };
 }
 
-/// The compiled pattern, built once and shared.
+/// The compiled patterns, each built once and shared, one for every ruleset type.
 ///
 /// A `OnceLock`, not a `static mut` behind an `is_none()` check.
 ///
@@ -35,64 +40,118 @@ macro_rules! __str_ext__instance_words_vec {
 /// reached for. `once_cell` is optional here and gated on the two performance features, so
 /// naming it unconditionally broke every build with a regex backend and neither flag. The
 /// standard library's has been available since 1.70, which is what `rust-version` says.
+///
+/// The static is one per backend module, and a generic function's statics are not one per type
+/// it is instantiated with, so the patterns are kept by the ruleset's `TypeId`. A single pattern
+/// was what the first ruleset to be resolved compiled, handed to every ruleset after it.
 #[macro_export]
 macro_rules! __str_ext__cache_static_regex {
     ($regex:ty, $selfty:ty) => {
         #[cfg(not(feature = "optimize_for_memory"))]
-        static REGEX: ::std::sync::OnceLock<$regex> = ::std::sync::OnceLock::new();
+        static REGEXES: ::std::sync::OnceLock<
+            ::std::sync::RwLock<
+                ::std::collections::HashMap<::std::any::TypeId, ::std::sync::Arc<$regex>>,
+            >,
+        > = ::std::sync::OnceLock::new();
+
+        /// Returns the pattern shared by `R`, compiling it on the first call.
+        #[cfg(not(feature = "optimize_for_memory"))]
+        fn shared_regex<R>() -> ::std::sync::Arc<$regex>
+        where
+            R: ResolverRules + 'static,
+        {
+            try_shared_regex::<R>().unwrap_or_else(|error| panic!("{}", error))
+        }
 
-        /// Returns the shared pattern, compiling it on the first call.
+        /// [`shared_regex`], returning an error where it would panic. A pattern that fails to
+        /// compile is not kept, so each call after it fails the same way.
         ///
-        /// Two threads arriving together agree on the answer: one wins the initialisation
-        /// and the other waits for it and sees the winner's value.
+        /// Two threads arriving together may both compile it, and agree on the answer: the first
+        /// to store its pattern wins, and the other is handed the winner's.
         #[cfg(not(feature = "optimize_for_memory"))]
-        fn shared_regex<R>() -> &'static $regex
+        fn try_shared_regex<R>(
+        ) -> ::std::result::Result<::std::sync::Arc<$regex>, $crate::error::SegmentationError>
         where
             R: ResolverRules + 'static,
         {
-            REGEX.get_or_init(|| match <$selfty>::compile_rules() {
-                CompiledRules::Regex(r) => <$regex>::new(r.as_str())
-                    .expect("Expected valid regex pattern"),
-                _ => panic!("Compiled rules were not a Regex"),
-            })
+            let regexes = REGEXES.get_or_init(Default::default);
+            let key = ::std::any::TypeId::of::<R>();
+            // a panic elsewhere cannot leave a half-written pattern behind, so a poisoned lock
+            // is still a good one
+            if let Some(re) =
+                regexes.read().unwrap_or_else(::std::sync::PoisonError::into_inner).get(&key)
+            {
+                return Ok(::std::sync::Arc::clone(re));
+            }
+            let rules = RuleSet::of::<R>();
+            let re = $crate::__str_ext__try_compile_regex!($regex, $selfty, &rules)?;
+            let mut regexes = regexes.write().unwrap_or_else(::std::sync::PoisonError::into_inner);
+            let re = regexes.entry(key).or_insert_with(|| ::std::sync::Arc::new(re));
+            Ok(::std::sync::Arc::clone(re))
         }
     };
 }
 
+/// Binds `$re_ident` to the pattern compiled from `$selfty`'s own rules.
+///
+/// Shared and compiled once, unless `optimize_for_memory` asks for it to be compiled for each call
+/// and dropped afterwards.
 #[macro_export]
-macro_rules! __str_ext__init_capture_iter {
-    (plain $re_ident:ident, $regex:ty, $selfty:ty, $iter:ident, $s:expr) => {
+macro_rules! __str_ext__init_regex {
+    ($re_ident:ident, $regex:ty, $selfty:ty) => {
         #[cfg(feature = "optimize_for_memory")]
-        let $re_ident = match <$selfty>::compile_rules() {
-            CompiledRules::Regex(r) => <$regex>::new(r.as_str()).expect(
-                "Expected valid \
-            regex pattern",
-            ),
-            _ => panic!("Compiled rules were not a Regex"),
+        let $re_ident = &{
+            let rules = RuleSet::of::<R>();
+            $crate::__str_ext__try_compile_regex!($regex, $selfty, &rules)
+                .unwrap_or_else(|error| panic!("{}", error))
         };
-
-        #[cfg(not(feature = "optimize_for_memory"))]
-        let $re_ident = shared_regex::<R>();
         #[cfg(not(feature = "optimize_for_memory"))]
-        let $iter = $re_ident.captures_iter($s);
-        #[cfg(feature = "optimize_for_memory")]
-        let $iter = $re_ident.captures_iter($s);
+        let $re_ident = &*shared_regex::<R>();
     };
-    (fancy $re_ident:ident, $regex:ty, $selfty:ty, $iter:ident, $s:expr) => {
+}
+
+/// [`__str_ext__init_regex`], returning from the enclosing function with the error where that
+/// would panic.
+#[macro_export]
+macro_rules! __str_ext__try_init_regex {
+    ($re_ident:ident, $regex:ty, $selfty:ty) => {
         #[cfg(feature = "optimize_for_memory")]
-        let $re_ident = match <$selfty>::compile_rules() {
-            CompiledRules::Regex(r) => <$regex>::new(r.as_str()).expect(
-                "Expected valid \
-            fancy_regex pattern",
-            ),
-            _ => panic!("Compiled rules were not a Regex"),
+        let $re_ident = &{
+            let rules = RuleSet::of::<R>();
+            $crate::__str_ext__try_compile_regex!($regex, $selfty, &rules)?
         };
-
-        // Since split function is not available in fancy_regex
-        // we do it manually using find_iter
         #[cfg(not(feature = "optimize_for_memory"))]
-        let $iter = shared_regex::<R>().find_iter($s);
-        #[cfg(feature = "optimize_for_memory")]
-        let $iter = $re_ident.find_iter($s);
+        let $re_ident = &*try_shared_regex::<R>()?;
+    };
+}
+
+/// Binds `$re_ident` to the pattern compiled from `$rules`, a ruleset given at run time.
+#[macro_export]
+macro_rules! __str_ext__compile_regex {
+    ($re_ident:ident, $regex:ty, $selfty:ty, $rules:expr) => {
+        let $re_ident = &$crate::__str_ext__try_compile_regex!($regex, $selfty, $rules)
+            .unwrap_or_else(|error| panic!("{}", error));
+    };
+}
+
+/// The pattern compiled from `$rules`, or a [`SegmentationError`](crate::error::SegmentationError)
+/// saying why it is not one, where the backend's const `BACKEND` names it.
+#[macro_export]
+macro_rules! __str_ext__try_compile_regex {
+    ($regex:ty, $selfty:ty, $rules:expr) => {
+        match <$selfty>::compile_rule_set($rules) {
+            CompiledRules::Regex(pattern) => <$regex>::new(pattern.as_str()).map_err(|error| {
+                $crate::error::SegmentationError::InvalidPattern {
+                    backend: BACKEND,
+                    reason: error.to_string(),
+                    pattern,
+                }
+            }),
+            _ => Err($crate::error::SegmentationError::InvalidPattern {
+                backend: BACKEND,
+                pattern: String::new(),
+                reason: String::from("the compiled rules were not a regex"),
+            }),
+        }
     };
 }
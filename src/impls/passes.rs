@@ -0,0 +1,517 @@
+//! The passes either side of resolution: one over the whole input before it, one over the words
+//! after it.
+//!
+//! Resolution is where the backends differ, so the passes are kept out of it. Every backend hands
+//! its input through [`pre_pass`] before resolving it and its words through [`post_pass`]
+//! afterwards, and the same pass rules have the same effect whichever backend sits in between.
+//!
+//! Both passes work on characters that still know where they were in the input. A word the
+//! post-pass trims is still a slice of the input, and a word resolved from an input the pre-pass
+//! cut something out of can still be found in it.
+
+use std::borrow::Cow;
+use std::ops::Range;
+use std::vec;
+
+#[cfg(feature = "enhanced_accuracy")]
+use crate::impls::clusters;
+use crate::impls::compiled::Compiled;
+use crate::rules::ResolverProcessingRule::{Attach, BoundEnd, BoundStart, Remove};
+use crate::rules::{Direction, RemoveMode, RuleTarget, Scope};
+use crate::segment::{CharCursor, Segment};
+
+/// A character, and the byte offset in the input it was found at.
+pub(crate) type Located = (usize, char);
+
+/// The characters of `s` the pre-pass keeps, or `None` when there is nothing for it to do.
+///
+/// Before resolution there are no words yet, so the input is one word for the pre-pass, whatever
+/// the scope of a rule says, and only removals have anything to act on. The resolution pass's
+/// removals of what leads or trails the input are made here too, after the pre-pass's own.
+pub(crate) fn pre_pass(compiled: &Compiled, s: &str) -> Option<Vec<Located>> {
+    if compiled.pre_pass.is_empty() && compiled.input_ends.is_empty() {
+        return None;
+    }
+    let removals = compiled.pre_pass.iter().filter_map(|rule| match rule {
+        Remove(target, mode) => Some((target, mode)),
+        _ => None,
+    });
+    let input_ends = compiled.input_ends.iter().map(|(target, mode)| (target, mode));
+    let mut kept: Vec<Located> = s.char_indices().collect();
+    for (target, mode) in removals.chain(input_ends) {
+        let removed = select(mode, &covered(compiled, target, &kept));
+        let mut removed = removed.into_iter();
+        kept.retain(|_| !removed.next().unwrap_or(false));
+    }
+    Some(kept)
+}
+
+/// `segments`, the words resolved from `s`, with the post-pass run over them.
+///
+/// The resolution pass's attachments are made first, being the last of resolving: whether a token
+/// goes to the word before or after it depends on both, so it waits until both are known. Then the
+/// post-pass rules apply in order, each to what the one before it left. A removal takes characters
+/// out of words, and a word left with none is dropped; a bound splits words where its target starts
+/// or ends. A rule scoped to the full input reads the words as one sequence, so the leading run it
+/// removes may take all of the first word and carry on into the second.
+pub(crate) fn post_pass<'a>(
+    compiled: &Compiled,
+    s: &'a str,
+    segments: Vec<Segment<'a>>,
+) -> Vec<Segment<'a>> {
+    if compiled.attach.is_empty() && compiled.post_pass.is_empty() {
+        return segments;
+    }
+    let mut words: Vec<Vec<Located>> = segments.iter().map(|segment| located(s, segment)).collect();
+    for (target, direction) in &compiled.attach {
+        words = attached(compiled, target, *direction, words);
+    }
+    for rule in &compiled.post_pass {
+        match rule {
+            Remove(target, mode) => {
+                let covered: Vec<Vec<bool>> =
+                    words.iter().map(|word| covered(compiled, target, word)).collect();
+                let removed: Vec<Vec<bool>> = match mode {
+                    RemoveMode::Prepended(Scope::FullInput)
+                    | RemoveMode::Appended(Scope::FullInput)
+                    | RemoveMode::Ends(Scope::FullInput)
+                    | RemoveMode::Middle(Scope::FullInput) => {
+                        let mut flat = select(mode, &covered.concat()).into_iter();
+                        covered
+                            .iter()
+                            .map(|word| flat.by_ref().take(word.len()).collect())
+                            .collect()
+                    },
+                    _ => covered.iter().map(|word| select(mode, word)).collect(),
+                };
+                for (word, removed) in words.iter_mut().zip(removed) {
+                    let mut removed = removed.into_iter();
+                    word.retain(|_| !removed.next().unwrap_or(false));
+                }
+                words.retain(|word| !word.is_empty());
+            },
+            BoundStart(target) | BoundEnd(target) => {
+                let at_start = matches!(rule, BoundStart(_));
+                words = words
+                    .into_iter()
+                    .flat_map(|word| {
+                        let mut splits: Vec<usize> = tokens(compiled, target, &word)
+                            .into_iter()
+                            .map(|token| if at_start { token.start } else { token.end })
+                            .filter(|&at| at > 0 && at < word.len())
+                            .collect();
+                        splits.dedup();
+                        split(word, &splits)
+                    })
+                    .collect();
+            },
+            Attach(target, direction) => words = attached(compiled, target, *direction, words),
+        }
+    }
+
+    let mut chars = CharCursor::new(s);
+    words
+        .into_iter()
+        .map(|word| {
+            let (text, range) = rebuilt(s, &word);
+            let kind = compiled.kind_of(&text);
+            chars.segment(text, range, kind)
+        })
+        .collect()
+}
+
+/// `segment`, less what the resolution pass's removals scoped to a single word take from it, or
+/// nothing if they take all of it.
+///
+/// A removal within a word can only be decided once the word is complete: whether a character is
+/// in its middle depends on what comes after it, and where the word ends is up to the bounds
+/// further on. So rather than in the middle of resolving, these are applied to each word as the
+/// backend finishes it, the same way for every backend.
+pub(crate) fn within_word<'a>(
+    compiled: &Compiled,
+    s: &'a str,
+    segment: Segment<'a>,
+) -> Option<Segment<'a>> {
+    let mut word = located(s, &segment);
+    let len = word.len();
+    for (target, mode) in &compiled.within_word {
+        let removed = select(mode, &covered(compiled, target, &word));
+        let mut removed = removed.into_iter();
+        word.retain(|_| !removed.next().unwrap_or(false));
+    }
+    if word.is_empty() {
+        return None;
+    }
+    if word.len() == len {
+        return Some(segment);
+    }
+    let (text, range) = rebuilt(s, &word);
+    let count = |from: usize, to: usize| s.get(from..to).map_or(0, |cut| cut.chars().count());
+    let char_range = segment.char_range.start + count(segment.byte_range.start, range.start)
+        ..segment.char_range.end - count(range.end, segment.byte_range.end);
+    Some(Segment {
+        kind: compiled.kind_of(&text),
+        text,
+        byte_range: range,
+        char_range,
+    })
+}
+
+/// Whether the passes read all of the input before the first word is handed out: there is a
+/// pre-pass or a removal of what leads or trails the input before resolution, or an attachment or
+/// a post-pass after it.
+pub(crate) fn reads_ahead(compiled: &Compiled) -> bool {
+    !(compiled.pre_pass.is_empty()
+        && compiled.input_ends.is_empty()
+        && compiled.attach.is_empty()
+        && compiled.post_pass.is_empty())
+}
+
+/// A backend's words, with attachments made and the post-pass run over them if the ruleset has
+/// either.
+///
+/// Without them the backend's own iterator is passed through untouched, as lazy as it was. With
+/// them every word is resolved before the first is handed out, since an attachment has to see the
+/// word after a token and a rule scoped to the full input where the input ends.
+pub struct Passed<'a, I> {
+    inner: PassedInner<'a, I>,
+}
+
+enum PassedInner<'a, I> {
+    Through(I),
+    Collected(vec::IntoIter<Segment<'a>>),
+}
+
+impl<'a, I: Iterator<Item = Segment<'a>>> Passed<'a, I> {
+    pub(crate) fn new(compiled: &Compiled, s: &'a str, words: I) -> Self {
+        let inner = if compiled.attach.is_empty() && compiled.post_pass.is_empty() {
+            PassedInner::Through(words)
+        } else {
+            PassedInner::Collected(post_pass(compiled, s, words.collect()).into_iter())
+        };
+        Passed { inner }
+    }
+}
+
+impl<'a, I: Iterator<Item = Segment<'a>>> Iterator for Passed<'a, I> {
+    type Item = Segment<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Segment<'a>> {
+        match &mut self.inner {
+            PassedInner::Through(words) => words.next(),
+            PassedInner::Collected(words) => words.next(),
+        }
+    }
+}
+
+/// The characters of `segment`, each with where it lies in `s`.
+///
+/// A borrowed word is a slice of `s` at its range. An owned one had something removed from inside
+/// it, so its characters are found again in order within its range, which is where they all came
+/// from.
+fn located(s: &str, segment: &Segment) -> Vec<Located> {
+    match &segment.text {
+        Cow::Borrowed(text) => {
+            let start = segment.byte_range.start;
+            text.char_indices().map(|(at, c)| (start + at, c)).collect()
+        },
+        Cow::Owned(text) => {
+            let range = segment.byte_range.clone();
+            let mut rest = s.get(range.clone()).unwrap_or_default().char_indices();
+            let mut end = range.start;
+            text.chars()
+                .map(|c| match rest.find(|&(_, candidate)| candidate == c) {
+                    Some((at, _)) => {
+                        end = range.start + at;
+                        (end, c)
+                    },
+                    // not from its range after all; kept in order, and owned when rebuilt
+                    None => (end, c),
+                })
+                .collect()
+        },
+    }
+}
+
+/// The word `word` reads as, borrowed from `s` if its characters are one run of it, and its extent.
+fn rebuilt<'a>(s: &'a str, word: &[Located]) -> (Cow<'a, str>, Range<usize>) {
+    let (first, _) = word[0];
+    let (last, _) = word[word.len() - 1];
+    // the end of the input's character there, which is the word's own unless it was not found
+    let range = first..s[last..].chars().next().map_or(last, |c| last + c.len_utf8());
+    let text: String = word.iter().map(|&(_, c)| c).collect();
+    match s.get(range.clone()) {
+        Some(slice) if slice == text => (Cow::Borrowed(slice), range),
+        _ => (Cow::Owned(text), range),
+    }
+}
+
+/// `words` with each token of `target` split out of the word it was in and glued onto the word
+/// on the side `direction` picks.
+///
+/// Tokens headed the same way gather first, so `##tag` is one word, and a token with no word on
+/// its side, or none it touches for [`Direction::Auto`], stays a word of its own.
+fn attached(
+    compiled: &Compiled,
+    target: &RuleTarget,
+    direction: Direction,
+    words: Vec<Vec<Located>>,
+) -> Vec<Vec<Located>> {
+    let words: Vec<Vec<Located>> = words
+        .into_iter()
+        .flat_map(|word| {
+            let mut splits: Vec<usize> = tokens(compiled, target, &word)
+                .into_iter()
+                .flat_map(|token| [token.start, token.end])
+                .filter(|&at| at > 0 && at < word.len())
+                .collect();
+            splits.dedup();
+            split(word, &splits)
+        })
+        .collect();
+
+    let mut glued: Vec<Vec<Located>> = Vec::with_capacity(words.len());
+    // tokens on their way to the next word
+    let mut carried: Vec<Located> = Vec::new();
+    for (idx, word) in words.iter().enumerate() {
+        let is_token = covered(compiled, target, word).iter().all(|c| *c);
+        let next = words.get(idx + 1);
+        let goes = match direction {
+            _ if !is_token => None,
+            Direction::Auto => {
+                let prev = if carried.is_empty() { glued.last() } else { Some(&carried) };
+                auto(word, prev.map(Vec::as_slice), next.map(Vec::as_slice))
+            },
+            Direction::Previous if carried.is_empty() && glued.is_empty() => None,
+            Direction::Next if next.is_none() => None,
+            direction => Some(direction),
+        };
+        match goes {
+            Some(Direction::Next) => carried.extend_from_slice(word),
+            Some(Direction::Previous) if !carried.is_empty() => carried.extend_from_slice(word),
+            Some(Direction::Previous) => glued.last_mut().unwrap().extend_from_slice(word),
+            _ => {
+                carried.extend_from_slice(word);
+                glued.push(std::mem::take(&mut carried));
+            },
+        }
+    }
+    if !carried.is_empty() {
+        glued.push(carried);
+    }
+    glued
+}
+
+/// Where [`Direction::Auto`] sends `token`, between `prev` and `next`, if anywhere.
+fn auto(
+    token: &[Located],
+    prev: Option<&[Located]>,
+    next: Option<&[Located]>,
+) -> Option<Direction> {
+    let touches = |before: &[Located], after: &[Located]| match (before.last(), after.first()) {
+        (Some(&(at, c)), Some(&(next_at, _))) => at + c.len_utf8() == next_at,
+        _ => false,
+    };
+    let touches_prev = prev.is_some_and(|prev| touches(prev, token));
+    let touches_next = next.is_some_and(|next| touches(token, next));
+    let starts_upper = next.and_then(|next| next.first()).is_some_and(|&(_, c)| c.is_uppercase());
+    let prev_is_number = prev.is_some_and(|prev| prev.iter().all(|&(_, c)| c.is_numeric()));
+
+    if token.iter().all(|&(_, c)| c.is_numeric()) {
+        match (touches_prev, touches_next) {
+            (_, true) if starts_upper => Some(Direction::Next),
+            (true, _) => Some(Direction::Previous),
+            (false, true) => Some(Direction::Next),
+            (false, false) => None,
+        }
+    } else {
+        match (touches_prev, touches_next) {
+            (true, true) if prev_is_number => Some(Direction::Previous),
+            (true, true) | (false, true) => Some(Direction::Next),
+            (true, false) => Some(Direction::Previous),
+            (false, false) => None,
+        }
+    }
+}
+
+/// `word` cut before each index in `splits`, which are ascending and inside it.
+fn split(mut word: Vec<Located>, splits: &[usize]) -> Vec<Vec<Located>> {
+    let mut pieces = Vec::with_capacity(splits.len() + 1);
+    for &at in splits.iter().rev() {
+        pieces.push(word.split_off(at));
+    }
+    pieces.push(word);
+    pieces.reverse();
+    pieces
+}
+
+/// Which characters of `word` fall inside a stretch `target` picks out.
+fn covered(compiled: &Compiled, target: &RuleTarget, word: &[Located]) -> Vec<bool> {
+    let mut covered = vec![false; word.len()];
+    for token in tokens(compiled, target, word) {
+        covered[token].iter_mut().for_each(|c| *c = true);
+    }
+    covered
+}
+
+/// Of the characters `covered` marks, the ones `mode` removes.
+///
+/// The ends are runs: what `Prepended` removes is every covered character before the first one
+/// that is not, so `__init__` loses both leading underscores, not one.
+fn select(mode: &RemoveMode, covered: &[bool]) -> Vec<bool> {
+    let len = covered.len();
+    let leading = covered.iter().take_while(|c| **c).count();
+    let trailing = len - covered.iter().rev().take_while(|c| **c).count();
+    covered
+        .iter()
+        .enumerate()
+        .map(|(idx, &c)| {
+            c && match mode {
+                RemoveMode::None => false,
+                RemoveMode::All => true,
+                RemoveMode::Prepended(_) => idx < leading,
+                RemoveMode::Appended(_) => idx >= trailing,
+                RemoveMode::Ends(_) => idx < leading || idx >= trailing,
+                RemoveMode::Middle(_) => idx >= leading && idx < trailing,
+            }
+        })
+        .collect()
+}
+
+/// The stretches of `word` that `target` picks out, as ranges of indices into it.
+///
+/// A character target picks out each matching character on its own, a run target each whole run,
+/// a string each place it occurs, from the left without overlapping, and a word predicate the
+/// whole word or nothing of it. A case change is a point between two characters rather than
+/// either of them, so it picks out empty stretches, which a bound splits at and a removal finds
+/// nothing in. Under `enhanced_accuracy` a stretch takes in the whole of the grapheme clusters at
+/// its ends, so that removing `e` from `e\u{301}` takes its accent with it.
+fn tokens(compiled: &Compiled, target: &RuleTarget, word: &[Located]) -> Vec<Range<usize>> {
+    let found = characters(compiled, target, word);
+    #[cfg(feature = "enhanced_accuracy")]
+    let found = clusters::widen(word, found);
+    found
+}
+
+/// [`tokens`], character by character, whether or not that cuts a grapheme cluster.
+fn characters(compiled: &Compiled, target: &RuleTarget, word: &[Located]) -> Vec<Range<usize>> {
+    let singles = |is: &dyn Fn(char) -> bool| -> Vec<Range<usize>> {
+        word.iter()
+            .enumerate()
+            .filter(|(_, &(_, c))| is(c))
+            .map(|(idx, _)| idx..idx + 1)
+            .collect()
+    };
+    match target {
+        RuleTarget::Char(target) => singles(&|c| c == *target),
+        RuleTarget::PunctSpecialChar => singles(&|c| compiled.punct.contains(c)),
+        RuleTarget::NonPunctSpecialChar => singles(&|c| compiled.non_punct_special.contains(c)),
+        RuleTarget::Numerics => runs(word, 1, |c, _| c.is_numeric()),
+        RuleTarget::PunctSpecialCharRun => runs(word, 2, |c, prev| {
+            compiled.punct.contains(c) && prev.map_or(true, |prev| prev == c)
+        }),
+        RuleTarget::Acronym => {
+            compiled.acronyms(&word.iter().map(|&(_, c)| c).collect::<Vec<_>>())
+        },
+        RuleTarget::CaseChangeNonAcronym => (1..word.len())
+            .filter(|&idx| {
+                let (prev, c) = (word[idx - 1].1, word[idx].1);
+                let next = word.get(idx + 1).map(|&(_, next)| next);
+                compiled.starts_word(c)
+                    && (compiled.is_lower(prev)
+                        || (compiled.is_upper(prev)
+                            && next.is_some_and(|next| compiled.is_lower(next))))
+            })
+            .map(|idx| idx..idx)
+            .collect(),
+        RuleTarget::String(string) => {
+            let string: Vec<char> = string.chars().collect();
+            let mut found = Vec::new();
+            let mut idx = 0;
+            while !string.is_empty() && idx + string.len() <= word.len() {
+                let here = idx..idx + string.len();
+                if word[here.clone()].iter().map(|&(_, c)| c).eq(string.iter().copied()) {
+                    found.push(here);
+                    idx += string.len();
+                } else {
+                    idx += 1;
+                }
+            }
+            found
+        },
+        RuleTarget::Word(predicate) => {
+            let text: String = word.iter().map(|&(_, c)| c).collect();
+            Some(0..word.len())
+                .filter(|whole| !whole.is_empty() && predicate.matches(&text))
+                .into_iter()
+                .collect()
+        },
+    }
+}
+
+/// The maximal runs of at least `min_len` characters `belongs` accepts, given the one before.
+fn runs(
+    word: &[Located],
+    min_len: usize,
+    belongs: impl Fn(char, Option<char>) -> bool,
+) -> Vec<Range<usize>> {
+    let mut found = Vec::new();
+    let mut start = None;
+    for (idx, &(_, c)) in word.iter().enumerate() {
+        if let Some(run_start) = start {
+            if belongs(c, Some(word[idx - 1].1)) {
+                continue;
+            }
+            if idx - run_start >= min_len {
+                found.push(run_start..idx);
+            }
+            start = None;
+        }
+        if belongs(c, None) {
+            start = Some(idx);
+        }
+    }
+    if let Some(run_start) = start {
+        if word.len() - run_start >= min_len {
+            found.push(run_start..word.len());
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::sync::Arc;
+
+    use crate::impls::charwalk::Walk;
+    use crate::rules::RemoveMode::All;
+    use crate::rules::RuleSet;
+    use crate::rules::RuleTarget::Char;
+
+    use super::*;
+
+    /// How many words `Passed` reads from the walk under `rules` to hand out its first.
+    fn read_for_first_word(rules: &RuleSet) -> usize {
+        let s = "one two three four five";
+        let compiled = Arc::new(Compiled::of(rules));
+        let reads = Cell::new(0);
+        let words = Walk::new(s, Arc::clone(&compiled)).inspect(|_| reads.set(reads.get() + 1));
+        let first = Passed::new(&compiled, s, words).next().map(|segment| segment.text);
+        assert_eq!(first.as_deref(), Some("one"));
+        reads.get()
+    }
+
+    #[test]
+    fn passed_reads_one_word_without_attachments_or_a_post_pass() {
+        assert_eq!(read_for_first_word(&RuleSet::default()), 1);
+    }
+
+    #[test]
+    fn passed_reads_every_word_with_a_post_pass() {
+        let mut rules = RuleSet::default();
+        rules.post_pass_rules.push(Remove(Char('-'), All));
+        assert_eq!(read_for_first_word(&rules), 5);
+    }
+}
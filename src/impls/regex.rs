@@ -2,17 +2,35 @@ use std::marker::PhantomData;
 
 use regex::Regex as RE;
 
+use crate::capabilities::{removes_in_resolution, Capabilities, TargetKind};
+use crate::error::SegmentationError;
 use crate::rules::ResolverProcessingRule::{BoundEnd, BoundStart};
 use crate::rules::{
-    DefaultRules, RemoveMode, ResolverProcessingRule, ResolverRules, RuleTarget, Scope,
+    DefaultRules, RemoveMode, ResolverProcessingRule, ResolverRules, RuleSet, RuleTarget, Scope,
 };
+use crate::impls::compiled::{named_chars, CharSet, Compiled};
+use crate::impls::passes;
+use crate::segment::{place, CharCursor, Segment};
 use crate::{
-    CompiledRules, WordBoundResolverImpl, __str_ext__cache_static_regex,
-    __str_ext__init_capture_iter, __str_ext__instance_words_vec,
+    CompiledRules, WordBoundResolverImpl, __str_ext__cache_static_regex, __str_ext__compile_regex,
+    __str_ext__init_regex, __str_ext__instance_words_vec, __str_ext__try_compile_regex,
+    __str_ext__try_init_regex,
 };
 
 __str_ext__cache_static_regex!(RE, Regex::<R>);
 
+/// The name this backend goes by in a [`SegmentationError`].
+const BACKEND: &str = "regex";
+
+/// The targets resolution removes, with a class or a pattern of their own.
+const REMOVES: &[TargetKind] = &[
+    TargetKind::String,
+    TargetKind::Char,
+    TargetKind::Acronym,
+    TargetKind::PunctSpecialChar,
+    TargetKind::PunctSpecialCharRun,
+];
+
 pub struct Regex<R: ResolverRules = DefaultRules> {
     _phantom_data: PhantomData<R>,
 }
@@ -21,46 +39,254 @@ impl<R: ResolverRules> WordBoundResolverImpl<R> for Regex<R>
 where
     R: 'static,
 {
-    fn resolver(s: &str) -> Vec<String> {
+    type Iter<'a> = std::vec::IntoIter<Segment<'a>>;
+
+    fn iter(s: &str) -> Self::Iter<'_> {
+        let rules = RuleSet::of::<R>();
+        supported(&rules).unwrap_or_else(|error| panic!("{}", error));
+        __str_ext__init_regex!(re, RE, Regex::<R>);
+        Self::segments_by(s, &rules, re, &Compiled::of(&rules)).into_iter()
+    }
+
+    fn iter_with_rules<'a>(s: &'a str, rules: &RuleSet) -> Self::Iter<'a> {
+        supported(rules).unwrap_or_else(|error| panic!("{}", error));
+        __str_ext__compile_regex!(re, RE, Regex::<R>, rules);
+        Self::segments_by(s, rules, re, &Compiled::of(rules)).into_iter()
+    }
+
+    /// The ruleset, which the removals are read from, the pattern compiled from it, and the rest
+    /// of it as the passes and the segment kinds read it.
+    type Prepared = (RuleSet, RE, Compiled);
+
+    fn prepare(rules: &RuleSet) -> Self::Prepared {
+        Self::try_prepare(rules).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    fn try_prepare(rules: &RuleSet) -> Result<Self::Prepared, SegmentationError> {
+        supported(rules)?;
+        let re = __str_ext__try_compile_regex!(RE, Regex::<R>, rules)?;
+        Ok((rules.clone(), re, Compiled::of(rules)))
+    }
+
+    fn iter_prepared<'a>((rules, re, compiled): &Self::Prepared, s: &'a str) -> Self::Iter<'a> {
+        Self::segments_by(s, rules, re, compiled).into_iter()
+    }
+
+    /// Through the pattern shared by `R`, as [`resolver`](Self::resolver) is.
+    fn try_resolver(s: &str) -> Result<Vec<String>, SegmentationError> {
+        let rules = RuleSet::of::<R>();
+        supported(&rules)?;
+        __str_ext__try_init_regex!(re, RE, Regex::<R>);
+        let (case, locale) = (rules.case_mode, rules.locale);
+        let segments = Self::segments_by(s, &rules, re, &Compiled::of(&rules));
+        Ok(segments.iter().map(|segment| case.apply_in(&segment.text, locale)).collect())
+    }
+
+    /// Characters, strings, acronyms and punctuation to remove, and anything but whole words to
+    /// bound at, with the mode of a removal read for strings and punctuation alone.
+    fn capabilities() -> Capabilities {
+        Capabilities {
+            backend: BACKEND,
+            lazy: false,
+            removes: REMOVES,
+            remove_modes: &[
+                TargetKind::String,
+                TargetKind::PunctSpecialChar,
+                TargetKind::PunctSpecialCharRun,
+            ],
+            refuses: true,
+            bound_starts: TargetKind::WITHIN_WORDS,
+            bound_ends: TargetKind::WITHIN_WORDS,
+            one_sided_runs: false,
+            whole_strings: true,
+        }
+    }
+
+    /// The strings rules name, letters, digits, and any other character on its own, with the marks
+    /// that combine with it, such as the selector that shows `⚠️` as an emoji. A letter is any
+    /// word character the rules do not read as punctuation or special, in any script.
+    ///
+    /// A character some rule removes from within words is read as a letter, since the word it is
+    /// removed from has to be captured whole for there to be a within. Punctuation is the
+    /// exception: it separates words whatever else is said about it. Strings come first and the
+    /// longest of them first, so that each is captured whole wherever it occurs.
+    fn compile_rule_set(rules: &RuleSet) -> CompiledRules {
+        // the word characters, less digits, what the rules read as punctuation or special, and
+        // what a rule names outside ASCII, which is read by that rule
+        let punct = CharSet::punct(rules).class("");
+        let special = CharSet::special(rules).class("");
+        let named: String =
+            named_chars(&rules.resolution_pass_rules).filter(|c| !c.is_ascii()).collect();
+        let mut letters = format!(r"[\w--[\d_{}{}{}]]", punct, special, named);
+        let mut strings: Vec<&str> = Vec::new();
+        for rule in &rules.resolution_pass_rules {
+            if let ResolverProcessingRule::Remove(RuleTarget::Char(c), mode) = rule {
+                if mode.scope() == Some(&Scope::SingleWord)
+                    && !rules.punct_chars_non_regex.contains(*c)
+                {
+                    letters.push_str(&regex::escape(&c.to_string()));
+                }
+            }
+            if let Some(RuleTarget::String(string)) = rule.target() {
+                if !string.is_empty() && !strings.contains(&string.as_str()) {
+                    strings.push(string);
+                }
+            }
+        }
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        let strings: String = strings.iter().map(|string| regex::escape(string) + "|").collect();
+        CompiledRules::Regex(format!(r"({}[{}]+|\d+|(?s:.)\p{{M}}*)", strings, letters))
+    }
+}
+
+impl<R: ResolverRules> Regex<R>
+where
+    R: 'static,
+{
+    fn segments_by<'a>(
+        s: &'a str,
+        rules: &RuleSet,
+        re: &RE,
+        compiled: &Compiled,
+    ) -> Vec<Segment<'a>> {
+        let words = match passes::pre_pass(compiled, s) {
+            Some(kept) => {
+                let kept: String = kept.into_iter().map(|(_, c)| c).collect();
+                Self::words(&kept, rules, re, compiled)
+            },
+            None => Self::words(s, rules, re, compiled),
+        };
+        let mut placed = Vec::with_capacity(words.len());
+        let mut chars = CharCursor::new(s);
+        let mut from = 0;
+        for word in words {
+            let (word, range) = place(s, from, word);
+            from = range.end;
+            let kind = compiled.kind_of(&word);
+            let segment = chars.segment(word, range, kind);
+            if compiled.within_word.is_empty() {
+                placed.push(segment);
+            } else if let Some(segment) = passes::within_word(compiled, s, segment) {
+                placed.push(segment);
+            }
+        }
+        passes::post_pass(compiled, s, placed)
+    }
+
+    /// The words in the input's own case, before they are placed back in it.
+    fn words(s: &str, rules: &RuleSet, re: &RE, compiled: &Compiled) -> Vec<String> {
         __str_ext__instance_words_vec!(s, words);
-        __str_ext__init_capture_iter!(plain re, RE, Regex::<R>, captures_iter, s);
-
-        let punct_chars = R::punct_chars_allow_whitespace();
-        let non_punct_special_chars = R::non_punct_special_chars_allow_whitespace();
-        let puncts = RE::new(&format!(r"[{}]", &punct_chars)).expect(
-            "Expected a valid \
-        regex \
-        pattern \
-        for punct chars",
-        );
-        let non_punct_specials = RE::new(&format!(r"{}", &non_punct_special_chars)).expect(
-            "Expected\
-         a valid regex pattern for \
-        non-punct special chars",
-        );
+        let captures_iter = re.captures_iter(s);
+
+        let (puncts, non_punct_specials) =
+            classes(rules).expect("the classes are checked along with the rules");
         let mut attach_to_next = String::new();
         let mut remove_idxs: Vec<usize> = Vec::new();
 
-        let captures: Vec<_> = captures_iter.collect();
+        // a run of the same punctuation character is captured a character at a time, since the
+        // pattern has no backreference to match "the same one again" with, so it is put back
+        // together here, and read as a run rather than as punctuation from then on
+        let runs = !compiled.punct_run.is_inert();
+        let mut captures: Vec<(String, bool)> = Vec::new();
+        for cap in captures_iter {
+            let token = &cap[0];
+            let mut chars = token.chars();
+            if let (Some(c), None, Some((last, run))) =
+                (chars.next(), chars.next(), captures.last_mut())
+            {
+                if runs
+                    && compiled.punct.contains(c)
+                    && (*run || last.chars().count() == 1)
+                    && last.chars().all(|l| l == c)
+                {
+                    last.push(c);
+                    *run = true;
+                    continue;
+                }
+            }
+            // a string inside a run of letters is captured whole, as it is anywhere else
+            let mut from = 0;
+            if let Some(matcher) = compiled.string_matcher.as_ref().filter(|_| token.len() > 1) {
+                for found in matcher.find_iter(token) {
+                    if found.start() == 0 && found.end() == token.len() {
+                        break;
+                    }
+                    if found.start() > from {
+                        captures.push((token[from..found.start()].to_owned(), false));
+                    }
+                    captures.push((token[found.range()].to_owned(), false));
+                    from = found.end();
+                }
+            }
+            if from < token.len() {
+                captures.push((token[from..].to_owned(), false));
+            }
+        }
         let captures_len = captures.len();
         // let first = &captures[0][0];
         // let last = &captures[captures.len() - 1][0];
         // const SPLIT_IN_POST_MARKER: &str = "[[_S_]]";
         const SPLIT_IN_POST_MARKER: &str = " ";
-        for (idx, cap) in captures.iter().enumerate() {
+        // a string to start the next word with, and whether the next word closes up with the last
+        // over a string between them
+        let mut string_to_next = String::new();
+        let mut glue_next = false;
+        for (idx, (cap, run)) in captures.iter().enumerate() {
+            let run = *run;
+            let string_rules = compiled
+                .strings
+                .iter()
+                .find(|(string, _)| !run && string == cap)
+                .map(|(_, string_rules)| *string_rules);
+            let mut pending = std::mem::take(&mut string_to_next);
+            let glue = std::mem::take(&mut glue_next);
+            let follows = idx > 0 && !remove_idxs.contains(&(idx - 1));
+            let mut removed_string = false;
             let mut word = if !attach_to_next.is_empty() {
-                let res = format!("{}{}", attach_to_next, &cap[0]).to_owned();
+                let res = format!("{}{}", attach_to_next, cap).to_owned();
                 attach_to_next.clear();
                 res
             } else {
-                cap[0].to_owned()
+                cap.to_owned()
             };
-            for rule in R::resolution_pass_rules() {
+            for rule in rules.resolution_pass_rules.iter().cloned() {
+                // the characters of a string answer to its rules alone
+                if string_rules.is_some() != matches!(rule.target(), Some(RuleTarget::String(_))) {
+                    continue;
+                }
                 match rule {
+                    // removals within a word are applied to each word once it has been placed,
+                    // and of what leads or trails the input before the input is resolved
+                    ResolverProcessingRule::Remove(_, mode) if !removes_in_resolution(&mode) => {},
                     ResolverProcessingRule::Remove(target, mode) => match target {
                         RuleTarget::Char(c) => {
                             word = word.replace(c, "");
                         },
+                        RuleTarget::String(string) => {
+                            let at_end = idx == 0 || idx == captures_len - 1;
+                            let removes = match mode {
+                                RemoveMode::Middle(Scope::FullInput) => !at_end,
+                                RemoveMode::Ends(Scope::FullInput) => at_end,
+                                _ => true,
+                            };
+                            if word == string && removes {
+                                remove_idxs.push(idx);
+                                removed_string = true;
+                            }
+                        },
+                        RuleTarget::Acronym => {
+                            let chars: Vec<char> = word.chars().collect();
+                            let acronyms = compiled.acronyms(&chars);
+                            word = chars
+                                .iter()
+                                .enumerate()
+                                .filter(|(idx, _)| {
+                                    !acronyms.iter().any(|acronym| acronym.contains(idx))
+                                })
+                                .map(|(_, c)| c)
+                                .collect();
+                        },
+                        RuleTarget::PunctSpecialChar if run => {},
                         RuleTarget::PunctSpecialChar => match mode {
                             RemoveMode::All => {
                                 if puncts.is_match(&word) {
@@ -69,93 +295,112 @@ where
                                     word = puncts.replace_all(&word, "").to_string();
                                 }
                             },
-                            RemoveMode::Middle(scope) => match scope {
-                                Scope::FullInput => {
-                                    if idx != 0 && idx != captures_len - 1 {
-                                        if puncts.is_match(&word) {
-                                            remove_idxs.push(idx);
-                                        } else {
-                                            word = puncts.replace_all(&word, "").to_string();
-                                        }
+                            RemoveMode::Middle(Scope::FullInput) => {
+                                if idx != 0 && idx != captures_len - 1 {
+                                    if puncts.is_match(&word) {
+                                        remove_idxs.push(idx);
+                                    } else {
+                                        word = puncts.replace_all(&word, "").to_string();
                                     }
-                                },
-                                Scope::SingleWord => {
-                                    unimplemented!()
-                                },
+                                }
                             },
-                            RemoveMode::Ends(scope) => match scope {
-                                Scope::FullInput => {
-                                    if idx == 0 || idx == captures_len {
-                                        if puncts.is_match(&word) {
-                                            remove_idxs.push(idx);
-                                        } else {
-                                            word = puncts.replace_all(&word, "").to_string();
-                                        }
+                            RemoveMode::Ends(Scope::FullInput) => {
+                                if idx == 0 || idx == captures_len {
+                                    if puncts.is_match(&word) {
+                                        remove_idxs.push(idx);
+                                    } else {
+                                        word = puncts.replace_all(&word, "").to_string();
                                     }
-                                },
-                                Scope::SingleWord => {
-                                    unimplemented!()
-                                },
-                            },
-                            _ => {
-                                unimplemented!()
+                                }
                             },
+                            _ => unreachable!("skipped above"),
                         },
-                        _ => {
-                            unimplemented!()
+                        RuleTarget::PunctSpecialCharRun if run => match mode {
+                            RemoveMode::All => remove_idxs.push(idx),
+                            RemoveMode::Middle(Scope::FullInput) => {
+                                if idx != 0 && idx != captures_len - 1 {
+                                    remove_idxs.push(idx);
+                                }
+                            },
+                            RemoveMode::Ends(Scope::FullInput) => {
+                                if idx == 0 || idx == captures_len - 1 {
+                                    remove_idxs.push(idx);
+                                }
+                            },
+                            _ => unreachable!("skipped above"),
                         },
+                        RuleTarget::PunctSpecialCharRun => {},
+                        _ => unreachable!("rejected by `supported`"),
                     },
                     ResolverProcessingRule::BoundStart(target) => match target {
                         RuleTarget::Char(c) => {
                             let c_str = c.to_string();
                             if word == c_str {
-                                let i = s.find(&c_str).unwrap();
-                                if s.get(i + 1..i + 2).unwrap() == " " {
-                                    continue;
-                                } else {
-                                    remove_idxs.push(idx);
-                                    c_str.clone_into(&mut attach_to_next);
+                                // nothing to start where a space or the end of the input follows
+                                match captures.get(idx + 1) {
+                                    Some((next, _)) if next != " " => {
+                                        remove_idxs.push(idx);
+                                        c_str.clone_into(&mut attach_to_next);
+                                    },
+                                    _ => continue,
                                 }
                             }
                         },
+                        // read along with the string's other rules, below
+                        RuleTarget::String(_) => {},
                         RuleTarget::CaseChangeNonAcronym => {
                             let mut prev_was_lowcase: i8 = -1;
                             let mut prev_was_split = 1;
-                            let new = word.chars().fold(String::new(), |acc: String, c: char| {
-                                if prev_was_lowcase == 1 && c.is_uppercase() && prev_was_split == 0
+                            let within = within_acronyms(compiled, &word);
+                            let chars = word.chars().zip(within);
+                            let new = chars.fold(String::new(), |acc, (c, within)| {
+                                if within {
+                                    // no case change inside an acronym
+                                    prev_was_lowcase = if compiled.is_lower(c) { 1 } else { 0 };
+                                    prev_was_split =
+                                        if prev_was_split > 0 { prev_was_split - 1 } else { 0 };
+                                    return format!("{}{}", acc, c);
+                                }
+                                if prev_was_lowcase == 1
+                                    && compiled.starts_word(c)
+                                    && prev_was_split == 0
                                 {
                                     prev_was_lowcase = 0;
-                                    let acc_minus_one =
-                                        if acc.is_empty() { "" } else { &acc[0..acc.len() - 1] };
-                                    let acc_tail = if acc.is_empty() {
-                                        ""
-                                    } else {
-                                        &acc.chars().last().unwrap().to_string()
-                                    };
+                                    // the last character may be more than a byte, now that
+                                    // a letter is one in any script
+                                    let tail = acc.chars().next_back().map_or(0, char::len_utf8);
+                                    let (acc_minus_one, acc_tail) =
+                                        acc.split_at(acc.len() - tail);
                                     prev_was_split = 2;
                                     return format!(
                                         "{}{}{}{}",
                                         acc_minus_one, acc_tail, SPLIT_IN_POST_MARKER, c
                                     );
                                 } else if prev_was_lowcase == 0
-                                    && c.is_lowercase()
+                                    && compiled.is_lower(c)
                                     && prev_was_split == 0
                                 {
                                     prev_was_lowcase = 1;
-                                    let acc_minus_one =
-                                        if acc.is_empty() { "" } else { &acc[0..acc.len() - 1] };
-                                    let acc_tail = if acc.is_empty() {
-                                        ""
-                                    } else {
-                                        &acc.chars().last().unwrap().to_string()
-                                    };
+                                    // the last character may be more than a byte, now that
+                                    // a letter is one in any script
+                                    let tail = acc.chars().next_back().map_or(0, char::len_utf8);
+                                    let (acc_minus_one, acc_tail) =
+                                        acc.split_at(acc.len() - tail);
                                     prev_was_split = 2;
                                     return format!(
                                         "{}{}{}{}",
                                         acc_minus_one, SPLIT_IN_POST_MARKER, acc_tail, c
                                     );
                                 }
-                                prev_was_lowcase = if c.is_lowercase() { 1 } else { 0 };
+                                // a character without case, kept inside a word by a removal
+                                // within it, is no case change on either side
+                                prev_was_lowcase = if compiled.is_lower(c) {
+                                    1
+                                } else if compiled.starts_word(c) {
+                                    0
+                                } else {
+                                    -1
+                                };
                                 prev_was_split =
                                     if prev_was_split > 0 { prev_was_split - 1 } else { 0 };
                                 format!("{}{}", acc, c)
@@ -163,7 +408,7 @@ where
                             word = new;
                         },
                         RuleTarget::Acronym => {
-                            unimplemented!()
+                            word = marked_acronyms(compiled, &word, true, SPLIT_IN_POST_MARKER);
                         },
                         RuleTarget::PunctSpecialChar => {
                             // NOTE: already handled by prepass regex
@@ -176,26 +421,22 @@ where
                             // }
                         },
                         RuleTarget::Numerics => {
-                            if R::resolution_pass_rules().contains(&BoundEnd(RuleTarget::Numerics))
+                            if rules.resolution_pass_rules.contains(&BoundEnd(RuleTarget::Numerics))
                             {
                                 continue;
                             }
+                            // a word of digits and letters is a string or a word something is
+                            // removed from within, captured whole, and is no number to bound
                             if word.chars().all(|c: char| c.is_numeric()) {
                                 if idx == captures_len - 1 {
                                     continue;
                                 }
                                 remove_idxs.push(idx);
                                 word.clone_into(&mut attach_to_next);
-                            } else if word.chars().any(|c: char| c.is_numeric()) {
-                                panic!(
-                                    "We should never end up in a situation where a word is a \
-                                mix of numerics and alphabet (initial regex should take care of \
-                                that)"
-                                );
                             }
                         },
                         RuleTarget::NonPunctSpecialChar => {
-                            if R::resolution_pass_rules()
+                            if rules.resolution_pass_rules
                                 .contains(&BoundEnd(RuleTarget::NonPunctSpecialChar))
                             {
                                 continue;
@@ -206,14 +447,11 @@ where
                             }
                         },
                         RuleTarget::PunctSpecialCharRun => {
-                            // not expressed in the pattern: this backend does not implement
-                            // punctuation runs, charwalk does. Matching a run of the same
-                            // character needs a backreference, which the `regex` crate has none
-                            // of by design. Named so rule compilation stays total.
-                        },
-                        _ => {
-                            unimplemented!()
+                            // NOTE: a run is a capture of its own once put back together above,
+                            // so it is bounded on both sides already
                         },
+                        // read by the passes, once there are words
+                        RuleTarget::Word(_) => {},
                     },
                     ResolverProcessingRule::BoundEnd(target) => match target {
                         RuleTarget::Char(c) => {
@@ -231,13 +469,20 @@ where
                         },
                         RuleTarget::CaseChangeNonAcronym => {
                             let mut prev_was_lowcase: i8 = -1;
-                            let new = word.chars().fold(String::new(), |acc: String, c: char| {
+                            let within = within_acronyms(compiled, &word);
+                            let chars = word.chars().zip(within);
+                            let new = chars.fold(String::new(), |acc, (c, within)| {
+                                if within {
+                                    // no case change inside an acronym
+                                    prev_was_lowcase = if compiled.is_lower(c) { 1 } else { 0 };
+                                    return format!("{}{}", acc, c);
+                                }
                                 if prev_was_lowcase == -1 {
-                                    prev_was_lowcase = if c.is_lowercase() { 1 } else { 0 };
-                                } else if prev_was_lowcase == 1 && c.is_uppercase() {
+                                    prev_was_lowcase = if compiled.is_lower(c) { 1 } else { 0 };
+                                } else if prev_was_lowcase == 1 && compiled.starts_word(c) {
                                     prev_was_lowcase = 0;
                                     return format!("{}{}{}", acc, SPLIT_IN_POST_MARKER, c);
-                                } else if prev_was_lowcase == 0 && c.is_lowercase() {
+                                } else if prev_was_lowcase == 0 && compiled.is_lower(c) {
                                     prev_was_lowcase = 1;
                                     return format!("{}{}{}", acc, SPLIT_IN_POST_MARKER, c);
                                 }
@@ -246,9 +491,7 @@ where
                             word = new;
                         },
                         RuleTarget::Acronym => {
-                            // NOTE: maybe Case
-                            // ChangeNonAcronym should work here and this is
-                            //      unnecessary
+                            word = marked_acronyms(compiled, &word, false, SPLIT_IN_POST_MARKER);
                         },
                         RuleTarget::PunctSpecialChar => {
                             // NOTE: already handled by prepass regex
@@ -267,7 +510,7 @@ where
                             // }
                         },
                         RuleTarget::NonPunctSpecialChar => {
-                            if R::resolution_pass_rules()
+                            if rules.resolution_pass_rules
                                 .contains(&BoundStart(RuleTarget::NonPunctSpecialChar))
                             {
                                 continue;
@@ -284,7 +527,7 @@ where
                             }
                         },
                         RuleTarget::Numerics => {
-                            if R::resolution_pass_rules()
+                            if rules.resolution_pass_rules
                                 .contains(&BoundStart(RuleTarget::Numerics))
                             {
                                 continue;
@@ -298,11 +541,6 @@ where
                                     let prev = &(words[len - 1]);
                                     words[len - 1] = format!("{}{}", prev, &word);
                                 };
-                            } else if word.chars().any(|c: char| c.is_numeric()) {
-                                panic!(
-                                    "We should never end up in a situation where a word is a \
-                                mix of numerics and alphabet"
-                                );
                             }
                         },
                         _ => {},
@@ -310,8 +548,53 @@ where
                     _ => {},
                 }
             }
-            if !remove_idxs.contains(&idx) {
-                words.push(word.to_lowercase());
+            // a string kept whole belongs to the words either side of it, except where it bounds
+            // them; one removed leaves them to close up over where it was
+            if let Some(string_rules) = string_rules {
+                let (starts, ends) = (string_rules.bound_start, string_rules.bound_end);
+                let last = words.last_mut().filter(|last| follows && !last.trim().is_empty());
+                if removed_string {
+                    glue_next = !starts && !ends && last.is_some();
+                } else if let Some(last) = last.filter(|_| !starts) {
+                    remove_idxs.push(idx);
+                    last.push_str(&word);
+                    glue_next = !ends;
+                } else if !ends && idx != captures_len - 1 {
+                    remove_idxs.push(idx);
+                    string_to_next = std::mem::take(&mut pending) + &word;
+                }
+            }
+            // a string's last character is still the one before the word, for the rules that
+            // bound a word by what is either side of its start
+            let first = word.strip_prefix(SPLIT_IN_POST_MARKER).unwrap_or(&word).chars().next();
+            let bounded = (!pending.is_empty() || glue)
+                && captures[idx - 1].0.chars().next_back().zip(first).is_some_and(
+                    |(before, first)| bounds_between(rules, compiled, before, first),
+                );
+            if bounded {
+                if !pending.is_empty() {
+                    words.push(std::mem::take(&mut pending));
+                }
+                // no longer following on from the last word
+                glue_next = false;
+            }
+            // a split before the word's first character is none, once something leads it
+            if !pending.is_empty() || (glue && !bounded) {
+                if let Some(rest) = word.strip_prefix(SPLIT_IN_POST_MARKER) {
+                    word = rest.to_owned();
+                }
+            }
+            if remove_idxs.contains(&idx) {
+                // nothing left for the string to start, so it is a word of its own
+                if !pending.is_empty() {
+                    words.push(pending);
+                }
+            } else if let Some(last) = words.last_mut().filter(|last| {
+                glue && !bounded && !last.trim().is_empty() && !word.trim().is_empty()
+            }) {
+                last.push_str(&word);
+            } else {
+                words.push(pending + &word);
             }
         }
 
@@ -335,10 +618,71 @@ where
             })
             .collect()
     }
+}
+
+/// `Ok` unless this backend refuses a rule of `rules`, or a class of characters it reads them by
+/// does not compile.
+fn supported(rules: &RuleSet) -> Result<(), SegmentationError> {
+    Regex::<DefaultRules>::capabilities().supported(rules)?;
+    classes(rules).map(|_| ())
+}
 
-    fn compile_rules() -> CompiledRules {
-        CompiledRules::Regex(r"([a-zA-Z]+|\d+|[\W_])".into())
+/// The punctuation characters of `rules`, and their special characters, as patterns.
+fn classes(rules: &RuleSet) -> Result<(RE, RE), SegmentationError> {
+    let compile = |pattern: String| {
+        RE::new(&pattern).map_err(|error| SegmentationError::InvalidPattern {
+            backend: BACKEND,
+            reason: error.to_string(),
+            pattern,
+        })
+    };
+    let puncts = CharSet::punct(rules).class(&rules.punct_chars_allow_whitespace);
+    let puncts = compile(format!(r"[{}]", puncts))?;
+    let non_punct_specials = compile(rules.non_punct_special_chars_allow_whitespace())?;
+    Ok((puncts, non_punct_specials))
+}
+
+/// Whether a rule of `rules` bounds a word between `before` and `first`: a number, where it starts
+/// or ends, or a case change.
+fn bounds_between(rules: &RuleSet, compiled: &Compiled, before: char, first: char) -> bool {
+    let listed = |rule: &ResolverProcessingRule| rules.resolution_pass_rules.contains(rule);
+    let (numeric, starts_numeric) = (before.is_numeric(), first.is_numeric());
+    (!numeric && starts_numeric && listed(&BoundStart(RuleTarget::Numerics)))
+        || (numeric && !starts_numeric && listed(&BoundEnd(RuleTarget::Numerics)))
+        || (compiled.is_lower(before)
+            && compiled.starts_word(first)
+            && listed(&BoundStart(RuleTarget::CaseChangeNonAcronym)))
+}
+
+/// For each character of `word`, whether it is inside an acronym, past its first character.
+fn within_acronyms(compiled: &Compiled, word: &str) -> Vec<bool> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut within = vec![false; chars.len()];
+    if !compiled.acronym.is_inert() {
+        for acronym in compiled.acronyms(&chars) {
+            within[acronym.start + 1..acronym.end].fill(true);
+        }
     }
+    within
+}
+
+/// `word` with `marker` put before each of its acronyms, or after each, where it is not already at
+/// an end of the word.
+fn marked_acronyms(compiled: &Compiled, word: &str, before: bool, marker: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let acronyms = compiled.acronyms(&chars);
+    let mut marked = String::with_capacity(word.len() + acronyms.len() * marker.len());
+    for (idx, &c) in chars.iter().enumerate() {
+        if before && idx > 0 && acronyms.iter().any(|acronym| acronym.start == idx) {
+            marked.push_str(marker);
+        }
+        marked.push(c);
+        if !before && idx + 1 < chars.len() && acronyms.iter().any(|acronym| acronym.end == idx + 1)
+        {
+            marked.push_str(marker);
+        }
+    }
+    marked
 }
 
 #[cfg(test)]
@@ -367,4 +711,31 @@ mod tests {
             "Compiled pattern does not match expectations"
         );
     }
+
+    /// The default rules, under a type of their own so that nothing else fills their pattern in.
+    #[cfg(not(feature = "optimize_for_memory"))]
+    struct Untouched;
+
+    #[cfg(not(feature = "optimize_for_memory"))]
+    impl ResolverRules for Untouched {
+        fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+            DefaultRules::resolution_pass_rules()
+        }
+    }
+
+    /// The pattern `try_resolve` reads is the shared one: put one matching nothing there, and
+    /// every call after resolves by it rather than compiling the rules again.
+    #[test]
+    #[cfg(not(feature = "optimize_for_memory"))]
+    fn try_resolve_reuses_the_shared_pattern() {
+        let key = std::any::TypeId::of::<Untouched>();
+        let shared = std::sync::Arc::new(RE::new(r"[^\s\S]").unwrap());
+        REGEXES.get_or_init(Default::default).write().unwrap().insert(key, shared.clone());
+        for _ in 0..2 {
+            // a pattern matching nothing captures no word
+            assert_eq!(Regex::<Untouched>::try_resolver("fooBar"), Ok(vec![]));
+        }
+        let cached = REGEXES.get().unwrap().read().unwrap()[&key].clone();
+        assert!(std::sync::Arc::ptr_eq(&cached, &shared));
+    }
 }
@@ -0,0 +1,138 @@
+//! The charwalk, with the word boundaries of UAX #29 under the rules.
+//!
+//! The rules know ASCII, and beyond it punctuation and symbols by their categories: a letter
+//! outside ASCII bounds nothing unless a rule names it. So charwalk reads `東京タワー` as one word,
+//! where UAX #29 ends one after each ideograph. This backend walks the input the same way, but also
+//! ends a word wherever
+//! [Unicode Standard Annex #29](https://www.unicode.org/reports/tr29/) puts a word boundary
+//! beside a character the rules have no reading of: one outside ASCII, neither punctuation nor
+//! special, that no rule names, and not part of a string one does. Between two characters the
+//! rules do read, they alone decide, so on ASCII input and under any ruleset this resolves exactly
+//! as `Charwalk` does.
+//!
+//! The boundaries come from `unicode-segmentation`, which the `enhanced_accuracy` feature pulls in.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::vec;
+
+use unicode_segmentation::{UWordBoundIndices, UnicodeSegmentation};
+
+use crate::capabilities::{Capabilities, TargetKind};
+use crate::impls::charwalk::Walk;
+use crate::impls::compiled::Compiled;
+use crate::impls::passes::{self, Located, Passed};
+use crate::rules::{DefaultRules, ResolverRules, RuleSet};
+use crate::CompiledRules::NotApplicable;
+use crate::{CompiledRules, WordBoundResolverImpl};
+
+pub struct UnicodeWords<R: ResolverRules = DefaultRules> {
+    _phantom_data: PhantomData<R>,
+}
+
+impl<R: ResolverRules> WordBoundResolverImpl<R> for UnicodeWords<R> {
+    type Iter<'a> = Passed<'a, Walk<'a, Breaks<'a>>>;
+    type Prepared = Arc<Compiled>;
+
+    fn prepare(rules: &RuleSet) -> Arc<Compiled> {
+        Arc::new(Compiled::of(rules))
+    }
+
+    fn iter_prepared<'a>(prepared: &Arc<Compiled>, s: &'a str) -> Self::Iter<'a> {
+        let rules = Arc::clone(prepared);
+        let walk =
+            Walk::with_breaks(s, Arc::clone(prepared), |s, kept| Breaks::new(rules, s, kept));
+        Passed::new(prepared, s, walk)
+    }
+
+    /// What charwalk reads, which is everything but whole words.
+    fn capabilities() -> Capabilities {
+        Self::capabilities_for(&RuleSet::of::<R>())
+    }
+
+    /// Lazy unless the passes around the walk read all of the input.
+    fn capabilities_for(rules: &RuleSet) -> Capabilities {
+        Capabilities {
+            backend: "unicode",
+            lazy: !passes::reads_ahead(&Compiled::of(rules)),
+            removes: TargetKind::WITHIN_WORDS,
+            remove_modes: TargetKind::WITHIN_WORDS,
+            refuses: false,
+            bound_starts: TargetKind::WITHIN_WORDS,
+            bound_ends: TargetKind::WITHIN_WORDS,
+            one_sided_runs: true,
+            whole_strings: true,
+        }
+    }
+
+    fn compile_rule_set(_rules: &RuleSet) -> CompiledRules {
+        NotApplicable
+    }
+}
+
+/// The UAX #29 word boundaries the walk ends a word at, as the positions in the input of the
+/// characters after them.
+///
+/// Found as the walk is advanced, over the input; over what the pre-pass kept of it, all at once,
+/// where there is a pre-pass, so that the characters it closed up are read as neighbours.
+pub struct Breaks<'a> {
+    rules: Arc<Compiled>,
+    inner: BreaksInner<'a>,
+}
+
+enum BreaksInner<'a> {
+    Input(&'a str, UWordBoundIndices<'a>),
+    Kept(vec::IntoIter<usize>),
+}
+
+impl<'a> Breaks<'a> {
+    fn new(rules: Arc<Compiled>, s: &'a str, kept: Option<&[Located]>) -> Self {
+        let Some(kept) = kept else {
+            let inner = BreaksInner::Input(s, s.split_word_bound_indices());
+            return Breaks { rules, inner };
+        };
+        let text: String = kept.iter().map(|&(_, c)| c).collect();
+        let mut chars = kept.iter().zip(text.char_indices()).peekable();
+        let mut breaks = Vec::new();
+        let mut prev = None;
+        for (at, _) in text.split_word_bound_indices() {
+            // the kept characters up to the one the boundary is before, for what comes before it
+            while let Some((&(_, c), _)) = chars.next_if(|(_, (offset, _))| *offset < at) {
+                prev = Some(c);
+            }
+            let Some((&(from, c), _)) = chars.peek() else {
+                break;
+            };
+            if prev.is_some_and(|prev| unread(&rules, prev, c)) {
+                breaks.push(from);
+            }
+        }
+        Breaks { rules, inner: BreaksInner::Kept(breaks.into_iter()) }
+    }
+}
+
+impl<'a> Iterator for Breaks<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match &mut self.inner {
+            BreaksInner::Input(s, bounds) => bounds.find_map(|(at, _)| {
+                let prev = s[..at].chars().next_back()?;
+                let c = s[at..].chars().next()?;
+                unread(&self.rules, prev, c).then_some(at)
+            }),
+            BreaksInner::Kept(breaks) => breaks.next(),
+        }
+    }
+}
+
+/// Whether the boundary between `prev` and `c` is one the rules have no reading of, and so one UAX
+/// #29 decides: beside a character the rules read neither by set nor by name.
+#[inline]
+fn unread(rules: &Compiled, prev: char, c: char) -> bool {
+    let reads =
+        |c: char| c.is_ascii() || rules.punct.contains(c) || rules.non_punct_special.contains(c);
+    !(reads(prev) && reads(c))
+        && rules.char_rules(prev).is_none()
+        && rules.char_rules(c).is_none()
+}
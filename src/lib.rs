@@ -1,8 +1,20 @@
-use crate::rules::{DefaultRules, ResolverRules};
+use std::borrow::Cow;
 
+use crate::capabilities::Capabilities;
+use crate::error::SegmentationError;
+use crate::rules::{DefaultRules, ResolverRules, RuleSet};
+use crate::segment::Segment;
+
+pub mod capabilities;
+pub mod case;
+pub mod categories;
+pub mod error;
 pub mod impls;
+pub mod locale;
 pub mod resolver;
 pub mod rules;
+pub mod segment;
+pub mod segmenter;
 
 #[cfg(feature = "optimize_for_cpu")]
 pub(crate) const CHARS_PER_WORD_AVG: usize = 3;
@@ -10,9 +22,130 @@ pub(crate) const CHARS_PER_WORD_AVG: usize = 3;
 #[cfg(all(not(feature = "optimize_for_cpu"), feature = "optimize_for_memory"))]
 pub(crate) const CHARS_PER_WORD_AVG: u8 = 3;
 
+/// A segmentation backend.
+///
+/// Every method comes in two forms: one reading the ruleset from `R`, fixed at compile time, and
+/// one taking a [`RuleSet`] value, for rules that are only known at run time. The first is the
+/// second given `RuleSet::of::<R>()`, unless a backend has something better to do with a ruleset
+/// it knows in advance. The regex backends compile `R`'s pattern once and keep it for as long as
+/// the process runs, one for every ruleset type; a [`RuleSet`] value is compiled each time it is
+/// passed, and [`prepare`](Self::prepare) is how to compile one once.
 pub trait WordBoundResolverImpl<R: ResolverRules = DefaultRules> {
-    fn resolver(s: &str) -> Vec<String>;
-    fn compile_rules() -> CompiledRules;
+    /// The words of `s` one at a time, as they are asked for.
+    type Iter<'a>: Iterator<Item = Segment<'a>>;
+
+    /// The words of `s`, owned and cased as [`ResolverRules::case_mode`] says, which is lower
+    /// case unless the rules choose otherwise.
+    fn resolver(s: &str) -> Vec<String> {
+        let (case, locale) = (R::case_mode(), R::locale());
+        Self::resolver_borrowed(s).iter().map(|word| case.apply_in(word, locale)).collect()
+    }
+
+    /// The words of `s` as they appear in it, case untouched.
+    ///
+    /// A word is borrowed from `s` whenever it is one contiguous run of the input, which is every
+    /// word the default rules produce. A rule that removes characters from inside a word leaves
+    /// nothing to borrow, and that word is the one that comes back owned.
+    fn resolver_borrowed(s: &str) -> Vec<Cow<'_, str>> {
+        Self::segments(s).into_iter().map(|segment| segment.text).collect()
+    }
+
+    /// The words of `s` with where each lies in it, case untouched.
+    fn segments(s: &str) -> Vec<Segment<'_>> {
+        __str_ext__instance_words_vec!(s, words);
+        words.extend(Self::iter(s));
+        words
+    }
+
+    /// Starts segmenting `s`, doing no more of it than the iterator is advanced through.
+    ///
+    /// How lazy that is depends on the backend and the ruleset. `Charwalk` walks only as far as
+    /// the word it is asked for, unless a pass around the walk needs all of the input first; the
+    /// regex backends work out every word before handing out the first.
+    /// [`capabilities_for`](Self::capabilities_for) says which.
+    fn iter(s: &str) -> Self::Iter<'_> {
+        Self::iter_with_rules(s, &RuleSet::of::<R>())
+    }
+
+    /// [`resolver`](Self::resolver), under `rules`.
+    fn resolver_with_rules(s: &str, rules: &RuleSet) -> Vec<String> {
+        let (case, locale) = (rules.case_mode, rules.locale);
+        Self::resolver_borrowed_with_rules(s, rules)
+            .iter()
+            .map(|word| case.apply_in(word, locale))
+            .collect()
+    }
+
+    /// [`resolver_borrowed`](Self::resolver_borrowed), under `rules`.
+    fn resolver_borrowed_with_rules<'a>(s: &'a str, rules: &RuleSet) -> Vec<Cow<'a, str>> {
+        Self::segments_with_rules(s, rules).into_iter().map(|segment| segment.text).collect()
+    }
+
+    /// [`segments`](Self::segments), under `rules`.
+    fn segments_with_rules<'a>(s: &'a str, rules: &RuleSet) -> Vec<Segment<'a>> {
+        __str_ext__instance_words_vec!(s, words);
+        words.extend(Self::iter_with_rules(s, rules));
+        words
+    }
+
+    /// [`iter`](Self::iter), under `rules`.
+    fn iter_with_rules<'a>(s: &'a str, rules: &RuleSet) -> Self::Iter<'a> {
+        Self::iter_prepared(&Self::prepare(rules), s)
+    }
+
+    /// A ruleset in the form this backend reads it, worked out once and shareable between
+    /// threads.
+    type Prepared: Send + Sync;
+
+    /// Works out what this backend needs from `rules`, so that it is not redone for every input.
+    fn prepare(rules: &RuleSet) -> Self::Prepared;
+
+    /// [`iter`](Self::iter), under rules already prepared.
+    fn iter_prepared<'a>(prepared: &Self::Prepared, s: &'a str) -> Self::Iter<'a>;
+
+    /// [`prepare`](Self::prepare), returning an error where it would panic: on a rule this backend
+    /// does not support, or a pattern the rules make that does not compile.
+    fn try_prepare(rules: &RuleSet) -> Result<Self::Prepared, SegmentationError> {
+        Ok(Self::prepare(rules))
+    }
+
+    /// [`iter_prepared`](Self::iter_prepared), returning an error where it would panic.
+    fn try_iter_prepared<'a>(
+        prepared: &Self::Prepared,
+        s: &'a str,
+    ) -> Result<Self::Iter<'a>, SegmentationError> {
+        Ok(Self::iter_prepared(prepared, s))
+    }
+
+    /// [`resolver`](Self::resolver), returning an error where it would panic.
+    fn try_resolver(s: &str) -> Result<Vec<String>, SegmentationError> {
+        Self::try_resolver_with_rules(s, &RuleSet::of::<R>())
+    }
+
+    /// [`resolver_with_rules`](Self::resolver_with_rules), returning an error where it would
+    /// panic.
+    fn try_resolver_with_rules(s: &str, rules: &RuleSet) -> Result<Vec<String>, SegmentationError> {
+        let (case, locale) = (rules.case_mode, rules.locale);
+        let prepared = Self::try_prepare(rules)?;
+        let segments = Self::try_iter_prepared(&prepared, s)?;
+        Ok(segments.map(|segment| case.apply_in(&segment.text, locale)).collect())
+    }
+
+    /// What this backend reads of a ruleset, which [`validate`](capabilities::validate) lays
+    /// rulesets against. Where that turns on the ruleset, this answers for `R`'s.
+    fn capabilities() -> Capabilities;
+
+    /// [`capabilities`](Self::capabilities), for `rules`.
+    fn capabilities_for(_rules: &RuleSet) -> Capabilities {
+        Self::capabilities()
+    }
+
+    fn compile_rules() -> CompiledRules {
+        Self::compile_rule_set(&RuleSet::of::<R>())
+    }
+
+    /// [`compile_rules`](Self::compile_rules), for `rules`.
+    fn compile_rule_set(rules: &RuleSet) -> CompiledRules;
 }
 
 pub enum CompiledRules {
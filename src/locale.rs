@@ -0,0 +1,193 @@
+//! Casing as a language has it, where that is not Unicode's default.
+//!
+//! The standard library cases text the same way whatever language it is in, and for most
+//! languages that is right. Turkish and Azerbaijani pair a dotless `ı` with `I` and a dotted `İ`
+//! with `i`, so `DİYARBAKIR` lowercases to `diyarbakır` there rather than to `di̇yarbakir`.
+//! Lithuanian keeps the dot of an `i` that takes an accent, so `Ì` lowercases to `i̇̀`. A
+//! [`Locale`] on the ruleset has the owned output cased its way, from the tables here: nothing is
+//! looked up anywhere else.
+//!
+//! Where words are bounded is the same in every locale. What these languages pair differently is
+//! which upper case letter goes with which lower case one, not whether a letter is upper or lower
+//! case, so a case change is one in any of them.
+
+/// A language whose casing is not Unicode's default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// `tr`
+    Turkish,
+    /// `az`
+    Azerbaijani,
+    /// `lt`
+    Lithuanian,
+}
+
+/// The letters the Turkic languages pair apart from the default, each with its lower case.
+const TURKIC_LOWER: &[(char, char)] = &[('I', 'ı'), ('İ', 'i')];
+
+/// The same pairs, each lower case letter with its upper case.
+const TURKIC_UPPER: &[(char, char)] = &[('i', 'İ'), ('ı', 'I')];
+
+/// The accented capitals Lithuanian lowercases with the dot of the `i` kept under the accent.
+const LITHUANIAN_LOWER: &[(char, &str)] = &[
+    ('Ì', "i\u{307}\u{300}"),
+    ('Í', "i\u{307}\u{301}"),
+    ('Ĩ', "i\u{307}\u{303}"),
+];
+
+/// The capitals Lithuanian lowercases with a dot kept where an accent follows, each with its lower
+/// case.
+const LITHUANIAN_DOTTED: &[(char, char)] = &[('I', 'i'), ('J', 'j'), ('Į', 'į')];
+
+/// The soft-dotted letters of the Latin, Greek and Cyrillic alphabets, whose dot goes when an
+/// accent is put on them, and whose explicit dot Lithuanian drops from the upper case.
+const SOFT_DOTTED: &[char] = &[
+    'i', 'j', 'į', 'ɉ', 'ɨ', 'ʝ', 'ʲ', 'ϳ', 'і', 'ј', 'ᵢ', 'ᶖ', 'ᶤ', 'ᶨ', 'ḭ', 'ị', 'ⁱ', 'ⅈ', 'ⅉ',
+    'ⱼ',
+];
+
+/// The combining marks written above a letter, of the combining diacritical marks block.
+const ABOVE: &[(char, char)] = &[
+    ('\u{300}', '\u{314}'),
+    ('\u{33d}', '\u{344}'),
+    ('\u{346}', '\u{346}'),
+    ('\u{34a}', '\u{34c}'),
+    ('\u{350}', '\u{352}'),
+    ('\u{357}', '\u{357}'),
+    ('\u{35b}', '\u{35b}'),
+    ('\u{363}', '\u{36f}'),
+];
+
+/// The combining dot above, which an `i` has where Lithuanian writes it explicitly.
+const DOT_ABOVE: char = '\u{307}';
+
+/// Whether `c` is a combining mark written above a letter.
+fn is_above(c: char) -> bool {
+    ABOVE.iter().any(|&(start, end)| (start..=end).contains(&c))
+}
+
+/// Whether `c` is one of the combining diacritical marks, above a letter or not.
+fn is_diacritic(c: char) -> bool {
+    ('\u{300}'..='\u{36f}').contains(&c)
+}
+
+/// The first mark above the letter before `rest`, passing over the marks written elsewhere.
+fn mark_above(rest: impl Iterator<Item = char>) -> Option<char> {
+    for c in rest {
+        if is_above(c) {
+            return Some(c);
+        }
+        if !is_diacritic(c) {
+            break;
+        }
+    }
+    None
+}
+
+/// The value of `key` in `table`, if it is there.
+fn lookup<T: Copy>(table: &[(char, T)], key: char) -> Option<T> {
+    table.iter().find(|&&(listed, _)| listed == key).map(|&(_, value)| value)
+}
+
+impl Locale {
+    /// The locale a BCP 47 language tag is in, read off its language subtag: `tr-TR` and `tr` are
+    /// Turkish. `None` for a language cased the default way.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next()?;
+        match language.to_ascii_lowercase().as_str() {
+            "tr" => Some(Locale::Turkish),
+            "az" => Some(Locale::Azerbaijani),
+            "lt" => Some(Locale::Lithuanian),
+            _ => None,
+        }
+    }
+
+    fn is_turkic(&self) -> bool {
+        matches!(self, Locale::Turkish | Locale::Azerbaijani)
+    }
+
+    /// `word` in lower case.
+    pub fn to_lowercase(&self, word: &str) -> String {
+        let mut mapped = String::with_capacity(word.len());
+        // where a capital `I` took the dot above that follows it, which then goes
+        let mut dot_taken = false;
+        let mut chars = word.chars();
+        while let Some(c) = chars.next() {
+            if self.is_turkic() {
+                match c {
+                    'I' if mark_above(chars.clone()) == Some(DOT_ABOVE) => {
+                        mapped.push('i');
+                        dot_taken = true;
+                        continue;
+                    },
+                    DOT_ABOVE if dot_taken => {
+                        dot_taken = false;
+                        continue;
+                    },
+                    _ => {},
+                }
+                if let Some(lower) = lookup(TURKIC_LOWER, c) {
+                    mapped.push(lower);
+                    continue;
+                }
+            } else if let Some(lower) = lookup(LITHUANIAN_LOWER, c) {
+                mapped.push_str(lower);
+                continue;
+            } else if let Some(lower) = lookup(LITHUANIAN_DOTTED, c) {
+                mapped.push(lower);
+                if mark_above(chars.clone()).is_some() {
+                    mapped.push(DOT_ABOVE);
+                }
+                continue;
+            }
+            mapped.push(c);
+        }
+        // the rest as the default has it, which is where a final sigma is read
+        mapped.to_lowercase()
+    }
+
+    /// `word` in upper case.
+    pub fn to_uppercase(&self, word: &str) -> String {
+        let mut mapped = String::with_capacity(word.len());
+        // whether the letter the marks being read are on is soft-dotted, with no mark above yet
+        let mut soft_dotted = false;
+        for c in word.chars() {
+            if self.is_turkic() {
+                mapped.push(lookup(TURKIC_UPPER, c).unwrap_or(c));
+                continue;
+            }
+            if c == DOT_ABOVE && soft_dotted {
+                soft_dotted = false;
+                continue;
+            }
+            if is_above(c) {
+                soft_dotted = false;
+            } else if !is_diacritic(c) {
+                soft_dotted = SOFT_DOTTED.contains(&c);
+            }
+            mapped.push(c);
+        }
+        mapped.to_uppercase()
+    }
+
+    /// `word` case folded, as [`CaseMode::Fold`](crate::rules::CaseMode::Fold) folds it.
+    ///
+    /// Only the Turkic languages fold apart from the default: `I` to `ı` and `İ` to `i`, with `ı`
+    /// left as it is.
+    pub fn fold(&self, word: &str) -> String {
+        if !self.is_turkic() {
+            return crate::rules::CaseMode::Fold.apply(word);
+        }
+        let mut folded = String::with_capacity(word.len());
+        for c in word.chars() {
+            match lookup(TURKIC_LOWER, c) {
+                Some(lower) => folded.push(lower),
+                None if c == 'ı' => folded.push(c),
+                // through upper case and back, as the default does, which a final sigma comes
+                // out of as `σ` when the letter is read on its own
+                None => folded.extend(c.to_uppercase().flat_map(char::to_lowercase)),
+            }
+        }
+        folded
+    }
+}
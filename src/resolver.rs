@@ -1,7 +1,10 @@
+use std::borrow::Cow;
 use std::marker::PhantomData;
 
+use crate::error::SegmentationError;
 use crate::impls::charwalk::Charwalk;
-use crate::rules::{DefaultRules, ResolverRules};
+use crate::rules::{DefaultRules, ResolverRules, RuleSet};
+use crate::segment::Segment;
 use crate::WordBoundResolverImpl;
 
 pub struct WordBoundResolver<
@@ -17,7 +20,7 @@ pub struct WordBoundResolver<
 impl<'a, I: WordBoundResolverImpl<R>, R: ResolverRules> WordBoundResolver<'a, I, R> {
     pub fn new(s: &'a str) -> Self {
         Self {
-            _phantom_data: PhantomData::default(),
+            _phantom_data: PhantomData,
             input: s,
             words: Vec::new(),
         }
@@ -29,6 +32,64 @@ impl<'a, I: WordBoundResolverImpl<R>, R: ResolverRules> WordBoundResolver<'a, I,
         I::resolver(s)
     }
 
+    /// The words of `s` as slices of it, in the input's own case.
+    ///
+    /// What [`resolve`](Self::resolve) returns is this, cased by the rules and copied out. Callers
+    /// that go on to compare, hash or look the words up can skip both.
+    pub fn resolve_borrowed(s: &str) -> Vec<Cow<'_, str>> {
+        I::resolver_borrowed(s)
+    }
+
+    /// The words of `s` with their byte and char ranges in it, for pointing back at the input.
+    pub fn segments(s: &str) -> Vec<Segment<'_>> {
+        I::segments(s)
+    }
+
+    /// The segments of `s` one at a time, so a caller that needs only the first few, or is
+    /// looking for one, can stop there.
+    ///
+    /// Stopping early saves work only where the backend is lazy under the ruleset, as
+    /// [`capabilities_for`](WordBoundResolverImpl::capabilities_for) says. Even `Charwalk` resolves
+    /// every word before handing out the first when the ruleset has a pre-pass, attachments, a
+    /// post-pass or a removal of what leads or trails the input, since those need all of it.
+    pub fn iter(s: &str) -> I::Iter<'_> {
+        I::iter(s)
+    }
+
+    /// [`resolve`](Self::resolve), under a ruleset given at run time rather than `R`.
+    pub fn resolve_with_rules(s: &str, rules: &RuleSet) -> Vec<String> {
+        I::resolver_with_rules(s, rules)
+    }
+
+    /// [`resolve_borrowed`](Self::resolve_borrowed), under a ruleset given at run time.
+    pub fn resolve_borrowed_with_rules<'s>(s: &'s str, rules: &RuleSet) -> Vec<Cow<'s, str>> {
+        I::resolver_borrowed_with_rules(s, rules)
+    }
+
+    /// [`segments`](Self::segments), under a ruleset given at run time.
+    pub fn segments_with_rules<'s>(s: &'s str, rules: &RuleSet) -> Vec<Segment<'s>> {
+        I::segments_with_rules(s, rules)
+    }
+
+    /// [`iter`](Self::iter), under a ruleset given at run time.
+    pub fn iter_with_rules<'s>(s: &'s str, rules: &RuleSet) -> I::Iter<'s> {
+        I::iter_with_rules(s, rules)
+    }
+
+    /// [`resolve`](Self::resolve), returning an error where the backend would panic.
+    pub fn try_resolve(s: &str) -> Result<Vec<String>, SegmentationError> {
+        I::try_resolver(s)
+    }
+
+    /// [`resolve_with_rules`](Self::resolve_with_rules), returning an error where the backend
+    /// would panic, as it does on a rule it does not support.
+    pub fn try_resolve_with_rules(
+        s: &str,
+        rules: &RuleSet,
+    ) -> Result<Vec<String>, SegmentationError> {
+        I::try_resolver_with_rules(s, rules)
+    }
+
     #[inline]
     pub fn resolve_with<I2: WordBoundResolverImpl<R2>, R2: ResolverRules>(s: &str) -> Vec<String> {
         I2::resolver(s)
@@ -1,5 +1,8 @@
+use crate::categories::GeneralCategory;
+use crate::impls::compiled::CharSet;
+use crate::locale::Locale;
 use crate::rules::RemoveMode::{All, Middle};
-use crate::rules::ResolverProcessingRule::{BoundEnd, BoundStart, Remove};
+use crate::rules::ResolverProcessingRule::{Attach, BoundEnd, BoundStart, Remove};
 use crate::rules::RuleTarget::{
     Acronym, CaseChangeNonAcronym, Char, NonPunctSpecialChar, Numerics, PunctSpecialChar,
     PunctSpecialCharRun,
@@ -20,71 +23,48 @@ pub trait ResolverRules {
         String::from(r"\-_\.,:;\?!")
     }
 
-    fn non_punct_special_chars() -> String {
-        let mut exclude_chars = Self::punct_chars();
-
-        for rule in &Self::resolution_pass_rules() {
-            match rule {
-                Remove(Char(c), _) | BoundStart(Char(c)) | BoundEnd(Char(c)) => {
-                    exclude_chars.push_str(&format!("{}", *c));
-                },
-                _ => (),
-            }
-        }
+    /// The general categories read as punctuation beyond ASCII, where `punct_chars` cannot list
+    /// everything there is: dashes, connectors, and the rest of `…`, `、`, `·` and `¿`.
+    fn punct_categories() -> Vec<GeneralCategory> {
+        use GeneralCategory::{Pc, Pd, Po};
+        vec![Pc, Pd, Po]
+    }
+    /// The general categories read as special characters beyond ASCII, less those
+    /// [`punct_categories`](Self::punct_categories) takes: brackets, quotes and the symbols.
+    fn special_categories() -> Vec<GeneralCategory> {
+        use GeneralCategory::{Pe, Pf, Pi, Ps, Sc, Sk, Sm, So};
+        vec![Ps, Pe, Pi, Pf, Sm, Sc, Sk, So]
+    }
 
-        format!(r"[^a-zA-Z0-9{}]", exclude_chars)
+    fn non_punct_special_chars() -> String {
+        special_chars_class(
+            &Self::punct_chars(),
+            &Self::resolution_pass_rules(),
+            &Self::punct_categories(),
+            &Self::special_categories(),
+        )
     }
 
     fn non_punct_special_chars_non_regex() -> String {
-        // Everything named here is ASCII, so the excluded set is two words of bits rather than a
-        // hash set: this is called once per input, and hashing three dozen characters to answer
-        // "is this one excluded" costs more than the walk that follows it.
-        let mut excluded: u128 = 0;
-        let mut exclude = |c: char| {
-            let value = c as u32;
-            if value < 128 {
-                excluded |= 1u128 << value;
-            }
-        };
-        for c in Self::punct_chars_non_regex().chars() {
-            exclude(c);
-        }
-        for rule in &Self::resolution_pass_rules() {
-            match rule {
-                Remove(Char(c), _) | BoundStart(Char(c)) | BoundEnd(Char(c)) => exclude(*c),
-                _ => (),
-            }
-        }
-
-        // the ASCII punctuation blocks, between the ranges holding digits and letters
-        let mut result: String = String::with_capacity(32);
-        for i in (33..47).chain(58..64).chain(91..96).chain(123..127) {
-            if excluded & (1u128 << i) == 0 {
-                if let Some(c) = std::char::from_u32(i as u32) {
-                    result.push(c);
-                }
-            }
-        }
-        result
+        special_chars_listed(&Self::punct_chars_non_regex(), &Self::resolution_pass_rules())
     }
 
     fn non_punct_special_chars_allow_whitespace() -> String {
-        let mut exclude_chars = Self::punct_chars_allow_whitespace();
-
-        for rule in &Self::resolution_pass_rules() {
-            match rule {
-                Remove(Char(c), _) | BoundStart(Char(c)) | BoundEnd(Char(c)) => {
-                    exclude_chars.push_str(&format!("{}", *c));
-                },
-                _ => (),
-            }
-        }
-
-        format!(r"[^a-zA-Z0-9{}]", exclude_chars)
+        special_chars_class(
+            &Self::punct_chars_allow_whitespace(),
+            &Self::resolution_pass_rules(),
+            &Self::punct_categories(),
+            &Self::special_categories(),
+        )
     }
     /// These are operations and rules that are run on the entire input before we pass it on to the
     /// resolution step
     ///
+    /// The input is one word to this pass, so `Remove(Char('_'), Prepended(FullInput))` drops the
+    /// leading underscores of `__private` before anything is bounded. Only removals apply here,
+    /// since there are no words yet for a bound to separate. A word resolved from what the pass
+    /// left is still found in, and borrowed from, the original input where it can be.
+    ///
     /// When pre_pass_rules is empty, no pre-process pass will be run.
     fn pre_pass_rules() -> Vec<ResolverProcessingRule> {
         Vec::new()
@@ -96,10 +76,35 @@ pub trait ResolverRules {
     /// These are operations and rules that are run on the resolved output of the resolution pass,
     /// which should be a fairly finished vector of properly bounded words.
     ///
+    /// A removal takes characters out of the words, each word on its own for `SingleWord` scope and
+    /// the words read as one sequence for `FullInput`, and a word left empty is dropped: so
+    /// `Remove(PunctSpecialChar, All)` here drops the separators resolution kept as words of their
+    /// own. A bound splits words further where its target starts or ends. The rules run in order,
+    /// each on what the one before it left.
+    ///
     /// When post_pass_rules is empty, no post-process pass will be run.
     fn post_pass_rules() -> Vec<ResolverProcessingRule> {
         Vec::new()
     }
+    /// How the owned words are cased on the way out.
+    ///
+    /// Only the owned output is affected. The borrowed words and segments are always the input's
+    /// own case, since a slice of the input cannot be anything else.
+    fn case_mode() -> CaseMode {
+        CaseMode::Lower
+    }
+    /// The language whose casing the words are cased by. Where they are bounded is the same in
+    /// every language.
+    ///
+    /// `None`, the default, is Unicode's own casing, which is right for most languages. See
+    /// [`Locale`] for the ones it is not right for.
+    fn locale() -> Option<Locale> {
+        None
+    }
+    /// The fewest capitals an [`Acronym`](RuleTarget::Acronym) is made of.
+    fn acronym_min_len() -> usize {
+        2
+    }
 }
 
 pub struct DefaultRules;
@@ -135,33 +140,278 @@ impl ResolverRules for DefaultRules {
     }
 }
 
-#[derive(PartialEq)]
+/// The special characters as a regex class: within ASCII, anything not a letter, a digit,
+/// punctuation, or a character some rule names on its own, and beyond it, the special categories
+/// less the same.
+fn special_chars_class(
+    punct_chars: &str,
+    rules: &[ResolverProcessingRule],
+    punct_categories: &[GeneralCategory],
+    special_categories: &[GeneralCategory],
+) -> String {
+    let mut exclude_chars = punct_chars.to_owned();
+
+    for rule in rules {
+        if let Some(Char(c)) = rule.target() {
+            exclude_chars.push_str(&format!("{}", *c));
+        }
+    }
+
+    let beyond_ascii =
+        CharSet::special_from("", punct_chars, rules, punct_categories, special_categories);
+    let ascii = format!(r"[^a-zA-Z0-9{}\x{{80}}-\x{{10FFFF}}]", exclude_chars);
+    format!("[{}]", beyond_ascii.class(&ascii))
+}
+
+/// The special characters listed out: the ASCII punctuation blocks, less punctuation and the
+/// characters some rule names on its own.
+fn special_chars_listed(punct_chars: &str, rules: &[ResolverProcessingRule]) -> String {
+    // Everything named here is ASCII, so the excluded set is two words of bits rather than a
+    // hash set: this is called once per input, and hashing three dozen characters to answer
+    // "is this one excluded" costs more than the walk that follows it.
+    let mut excluded: u128 = 0;
+    let mut exclude = |c: char| {
+        let value = c as u32;
+        if value < 128 {
+            excluded |= 1u128 << value;
+        }
+    };
+    for c in punct_chars.chars() {
+        exclude(c);
+    }
+    for rule in rules {
+        if let Some(Char(c)) = rule.target() {
+            exclude(*c);
+        }
+    }
+
+    // the ASCII punctuation blocks, between the ranges holding digits and letters
+    let mut result: String = String::with_capacity(32);
+    for i in (33..47).chain(58..64).chain(91..96).chain(123..127) {
+        if excluded & (1u128 << i) == 0 {
+            if let Some(c) = std::char::from_u32(i as u32) {
+                result.push(c);
+            }
+        }
+    }
+    result
+}
+
+/// A ruleset as a value, for rules that are not known until the program runs.
+///
+/// [`ResolverRules`] fixes a ruleset at compile time, as a type; this holds the same things as
+/// data, so it can be built from configuration, changed, and handed to any backend through the
+/// `*_with_rules` methods. The fields are what the trait's methods return, and a type's ruleset
+/// becomes one of these with [`RuleSet::of`].
+///
+/// The special character sets are not stored: they follow from the punctuation, the categories and
+/// the rules, and are worked out from them when asked for, so they cannot fall out of step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleSet {
+    /// The punctuation characters as the contents of a regex class.
+    pub punct_chars: String,
+    /// The punctuation characters, listed.
+    pub punct_chars_non_regex: String,
+    /// The punctuation characters as the contents of a regex class, without whitespace.
+    pub punct_chars_allow_whitespace: String,
+    /// The general categories read as punctuation beyond ASCII.
+    pub punct_categories: Vec<GeneralCategory>,
+    /// The general categories read as special characters beyond ASCII.
+    pub special_categories: Vec<GeneralCategory>,
+    pub pre_pass_rules: Vec<ResolverProcessingRule>,
+    pub resolution_pass_rules: Vec<ResolverProcessingRule>,
+    pub post_pass_rules: Vec<ResolverProcessingRule>,
+    pub case_mode: CaseMode,
+    pub locale: Option<Locale>,
+    pub acronym_min_len: usize,
+}
+
+impl RuleSet {
+    /// The ruleset `R` describes.
+    pub fn of<R: ResolverRules>() -> Self {
+        RuleSet {
+            punct_chars: R::punct_chars(),
+            punct_chars_non_regex: R::punct_chars_non_regex(),
+            punct_chars_allow_whitespace: R::punct_chars_allow_whitespace(),
+            punct_categories: R::punct_categories(),
+            special_categories: R::special_categories(),
+            pre_pass_rules: R::pre_pass_rules(),
+            resolution_pass_rules: R::resolution_pass_rules(),
+            post_pass_rules: R::post_pass_rules(),
+            case_mode: R::case_mode(),
+            locale: R::locale(),
+            acronym_min_len: R::acronym_min_len(),
+        }
+    }
+
+    /// Sets the punctuation characters, in all three of the forms they are kept in.
+    ///
+    /// `chars` lists them plainly, as [`ResolverRules::punct_chars_non_regex`] does. A space
+    /// among them stands for whitespace in general where the backend reads a regex class.
+    pub fn set_punct_chars(&mut self, chars: &str) {
+        let mut class = String::new();
+        let mut class_without_whitespace = String::new();
+        for c in chars.chars() {
+            if c.is_whitespace() {
+                continue;
+            }
+            for escaped in [&mut class, &mut class_without_whitespace] {
+                // the characters regex syntax gives a meaning to, which is what both engines escape
+                if r"\.+*?()|[]{}^$#&-~".contains(c) {
+                    escaped.push('\\');
+                }
+                escaped.push(c);
+            }
+        }
+        if chars.chars().any(char::is_whitespace) {
+            class.push_str(r"\ \s");
+        }
+        self.punct_chars = class;
+        self.punct_chars_non_regex = chars.to_owned();
+        self.punct_chars_allow_whitespace = class_without_whitespace;
+    }
+
+    /// See [`ResolverRules::non_punct_special_chars`].
+    pub fn non_punct_special_chars(&self) -> String {
+        special_chars_class(
+            &self.punct_chars,
+            &self.resolution_pass_rules,
+            &self.punct_categories,
+            &self.special_categories,
+        )
+    }
+
+    /// See [`ResolverRules::non_punct_special_chars_non_regex`].
+    pub fn non_punct_special_chars_non_regex(&self) -> String {
+        special_chars_listed(&self.punct_chars_non_regex, &self.resolution_pass_rules)
+    }
+
+    /// See [`ResolverRules::non_punct_special_chars_allow_whitespace`].
+    pub fn non_punct_special_chars_allow_whitespace(&self) -> String {
+        special_chars_class(
+            &self.punct_chars_allow_whitespace,
+            &self.resolution_pass_rules,
+            &self.punct_categories,
+            &self.special_categories,
+        )
+    }
+}
+
+impl Default for RuleSet {
+    /// [`DefaultRules`], as a value.
+    fn default() -> Self {
+        RuleSet::of::<DefaultRules>()
+    }
+}
+
+/// What happens to the case of a word once it has been bounded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CaseMode {
+    /// As it was in the input: `JSONResponse` gives `JSON` and `Response`.
+    Preserve,
+    /// Lower case, which is what the crate has always returned.
+    #[default]
+    Lower,
+    /// Upper case.
+    Upper,
+    /// Unicode-aware case folding, for comparing words rather than displaying them.
+    ///
+    /// Folding differs from lowercasing where a letter's lower case is not its canonical caseless
+    /// form: `ß` folds to `ss`, a final `ς` to `σ`, the `ﬁ` ligature to `fi`. The standard library
+    /// has no folding table, so this goes through upper case and back, which is where those
+    /// letters meet their folded forms.
+    Fold,
+}
+
+impl CaseMode {
+    /// `word`, cased as this mode says.
+    pub fn apply(&self, word: &str) -> String {
+        self.apply_in(word, None)
+    }
+
+    /// `word`, cased as this mode says the way `locale` cases it, or Unicode's way without one.
+    pub fn apply_in(&self, word: &str, locale: Option<Locale>) -> String {
+        if let Some(locale) = locale {
+            return match self {
+                CaseMode::Preserve => word.to_owned(),
+                CaseMode::Lower => locale.to_lowercase(word),
+                CaseMode::Upper => locale.to_uppercase(word),
+                CaseMode::Fold => locale.fold(word),
+            };
+        }
+        match self {
+            CaseMode::Preserve => word.to_owned(),
+            CaseMode::Lower => word.to_lowercase(),
+            CaseMode::Upper => word.to_uppercase(),
+            CaseMode::Fold => {
+                let folded = word.to_uppercase().to_lowercase();
+                // lowercasing puts a final sigma back at the end of the word, and folding does not
+                if folded.contains('ς') {
+                    folded.replace('ς', "σ")
+                } else {
+                    folded
+                }
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Scope {
     SingleWord,
     FullInput, // i.e prepends only considered for first word, and appends for the last
 }
 
-#[derive(PartialEq)]
+/// Which of a target's occurrences a removal takes.
+///
+/// The ends are runs: `Prepended` takes every occurrence before the first character that is not
+/// one, so `__init` loses both underscores, and `Appended` the same from the other end. Within a
+/// word, and in the passes, `Ends` and `Middle` divide a target's occurrences by the same runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RemoveMode {
     None,
+    /// What leads the input, or each word.
     Prepended(Scope),
+    /// What trails the input, or each word.
     Appended(Scope),
     Ends(Scope),
     Middle(Scope),
     All,
 }
 
-// pub enum IncludeMode {
-//     Dedicated, // as its own word, separately
-//     Attach(Direction),
-// }
+impl RemoveMode {
+    /// The scope the mode is read in, if it has one.
+    pub fn scope(&self) -> Option<&Scope> {
+        match self {
+            RemoveMode::Prepended(scope)
+            | RemoveMode::Appended(scope)
+            | RemoveMode::Ends(scope)
+            | RemoveMode::Middle(scope) => Some(scope),
+            RemoveMode::None | RemoveMode::All => None,
+        }
+    }
+}
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RuleTarget {
-    Word,
+    /// Whole words the predicate picks out, read after resolution: by the post-pass, by
+    /// attachments and by removals scoped to a single word. The pre-pass reads the input as one
+    /// word.
+    ///
+    /// `Remove(Word(In(stop_words)), All)` in the post-pass drops stop-words, and
+    /// `Attach(Word(In(vec!["i".into()])), Next)` makes `i/o`, split at the slash, into `io`.
+    Word(WordPredicate),
+    /// A string of characters, bounded or removed as a whole wherever it occurs. Where strings
+    /// overlap the longest one starting first is taken. Its characters answer to its rules alone,
+    /// so `Remove(String("->".into()), All)` closes up `a->b` into `ab` unless a bound on the
+    /// string keeps the two apart.
     String(String),
     Char(char),
     Numerics,
+    /// A run of capitals, at least [`ResolverRules::acronym_min_len`] of them. Where a lower case
+    /// letter follows, the last capital starts the next word, as the `P` of `HTMLParser` does,
+    /// unless the letter is a lone `s`: `URLs` is the plural of an acronym rather than `UR` and
+    /// `Ls`. A case change inside an acronym is not one.
     Acronym,
     PunctSpecialChar,
     /// A run of two or more of the same punctuation character, read as one token rather than as
@@ -172,27 +422,56 @@ pub enum RuleTarget {
     CaseChangeNonAcronym,
 }
 
-#[derive(PartialEq)]
+/// Which words a [`RuleTarget::Word`] picks out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WordPredicate {
+    /// Any word.
+    Any,
+    /// The words listed, regardless of case.
+    In(Vec<String>),
+    /// Words of fewer characters than this.
+    ShorterThan(usize),
+}
+
+impl WordPredicate {
+    /// Whether `word` is one of the words picked out.
+    pub fn matches(&self, word: &str) -> bool {
+        let lowercase = |word: &str| word.chars().flat_map(char::to_lowercase).collect::<Vec<_>>();
+        match self {
+            WordPredicate::Any => true,
+            WordPredicate::In(words) => {
+                let word = lowercase(word);
+                words.iter().any(|listed| lowercase(listed) == word)
+            },
+            WordPredicate::ShorterThan(len) => word.chars().count() < *len,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ResolverProcessingRule {
     Remove(RuleTarget, RemoveMode),
     BoundStart(RuleTarget),
     BoundEnd(RuleTarget),
+    /// The target as a token of its own, glued onto the word before or after it.
+    ///
+    /// `Attach(Char('#'), Next)` makes `hashtag#rust` into `hashtag` and `#rust`, and
+    /// `Attach(Char('%'), Previous)` makes `50%` one word, whether or not anything else bounds the
+    /// character. With nothing on the side it goes to, the token stays a word of its own.
+    Attach(RuleTarget, Direction),
 }
 
 impl ResolverProcessingRule {
     pub fn target(&self) -> Option<&RuleTarget> {
-        if let Remove(target, _) = self {
-            return Some(target);
-        } else if let BoundStart(target) = self {
-            return Some(target);
-        } else if let BoundEnd(target) = self {
-            return Some(target);
+        match self {
+            Remove(target, _) | BoundStart(target) | BoundEnd(target) | Attach(target, _) => {
+                Some(target)
+            },
         }
-        return None;
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     Previous,
     Next,
@@ -202,5 +481,11 @@ pub enum Direction {
     /// For numerics, we'd consider if the next word starts with a case change,
     /// and if it does, it's probably meant to go to next, but if there's
     /// a case change prior, or no case, probably goes to previous
+    ///
+    /// Read off the input, a token only goes to a word it touches, with nothing between them. A
+    /// number goes to the next word if it touches one starting in upper case, as in `2FA`, and
+    /// otherwise to whichever it touches, the previous first, as in `v2`. Anything else touching
+    /// both goes to the previous word if that is a number, as in `50%off`, and to the next if not,
+    /// as in `a#b`.
     Auto,
 }
@@ -0,0 +1,144 @@
+//! Where in the input each word came from.
+//!
+//! A word on its own says nothing about its position, and the same word can occur more than once,
+//! so finding it again afterwards is guesswork. The backends know where each word starts and ends
+//! while they are cutting it out, so they hand that over with it.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// One word of the input, and where it lies in it.
+///
+/// The ranges are the word's extent in the original input: from its first kept character to the
+/// end of its last. A removal rule that drops characters from inside a word does not shrink its
+/// range, only its text, so highlighting the range highlights everything the word was cut from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment<'a> {
+    /// The word, in the input's own case; borrowed unless something was removed from inside it.
+    pub text: Cow<'a, str>,
+    /// Byte offsets into the input, for slicing it.
+    pub byte_range: Range<usize>,
+    /// Char offsets into the input, for anything that counts in characters.
+    pub char_range: Range<usize>,
+    /// Which rule target the word answers to.
+    pub kind: SegmentKind,
+}
+
+/// What a segment is, in terms of the rule target that bounds it.
+///
+/// The kind is read off the segment against the ruleset that produced it, the same way for every
+/// backend, so the backends agree on it wherever they agree on the segment. A target the ruleset
+/// does not mention does not classify anything: without a `Numerics` rule, `123` is a `Word`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SegmentKind {
+    /// Anything not more specifically one of the others.
+    Word,
+    /// Digits only, bounded by `Numerics`.
+    Numeric,
+    /// Upper case letters only, two or more of them, bounded by `Acronym`.
+    Acronym,
+    /// Punctuation only, as the ruleset counts punctuation.
+    Punct,
+    /// Two or more of the same punctuation character, bounded by `PunctSpecialCharRun`.
+    PunctRun,
+    /// Neither letters, digits nor punctuation: `*`, `+`, an emoji.
+    Special,
+    /// A word led by a character the ruleset bounds a word's start on, or attaches to the next
+    /// word, as `#` in `#rust`.
+    Prefixed(char),
+    /// A word closed by a character the ruleset bounds a word's end on, or attaches to the
+    /// previous word, as `%` in `50%`.
+    Suffixed(char),
+}
+
+impl<'a> Segment<'a> {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Converts byte offsets to char offsets, for offsets that only move forward.
+///
+/// Counting from the start of the input for every word would make the conversion quadratic, and
+/// the backends visit their words in order anyway, so this counts only what lies between one
+/// offset and the next.
+pub(crate) struct CharCursor<'a> {
+    input: &'a str,
+    byte: usize,
+    char: usize,
+}
+
+impl<'a> CharCursor<'a> {
+    pub(crate) fn new(input: &'a str) -> Self {
+        CharCursor {
+            input,
+            byte: 0,
+            char: 0,
+        }
+    }
+
+    /// The char offset of `byte`, which is no earlier than the last one asked about.
+    #[inline]
+    pub(crate) fn to(&mut self, byte: usize) -> usize {
+        self.char += self.input[self.byte..byte].chars().count();
+        self.byte = byte;
+        self.char
+    }
+
+    /// A segment for the word `text`, found at `byte_range` of the input.
+    #[inline]
+    pub(crate) fn segment(
+        &mut self,
+        text: Cow<'a, str>,
+        byte_range: Range<usize>,
+        kind: SegmentKind,
+    ) -> Segment<'a> {
+        let char_range = self.to(byte_range.start)..self.to(byte_range.end);
+        Segment {
+            text,
+            byte_range,
+            char_range,
+            kind,
+        }
+    }
+}
+
+/// Finds `word` in `s` at or after `from`, and borrows it from there if it reads as one run.
+///
+/// A word built as a string does not carry where it came from: the `regex` backend builds them by
+/// stitching captures together and cutting them apart again, and any backend resolving what the
+/// pre-pass left of an input resolves a copy of it. Every character such a word keeps does come
+/// from the input and in order, though, so matching the word's characters forward from where the
+/// last one ended finds it again. A word whose characters are not one run of the input, because
+/// something inside it was removed, stays owned.
+///
+/// Returns the word and its extent in the input, which is empty at `from` for a word that could not
+/// be found at all.
+#[cfg(any(feature = "use_regex", feature = "use_fancy_regex", feature = "benchmark"))]
+pub(crate) fn place(s: &str, from: usize, word: String) -> (Cow<'_, str>, Range<usize>) {
+    let mut rest = s[from..].char_indices();
+    let mut start = None;
+    let mut end = from;
+    let mut contiguous = true;
+    for c in word.chars() {
+        match rest.find(|&(_, candidate)| candidate == c) {
+            Some((at, found)) => {
+                let at = from + at;
+                if start.is_none() {
+                    start = Some(at);
+                } else if at != end {
+                    contiguous = false;
+                }
+                end = at + found.len_utf8();
+            },
+            // not from the input in order after all, so there is nowhere to borrow it from
+            None => return (Cow::Owned(word), from..from),
+        }
+    }
+    match start {
+        Some(start) if contiguous => (Cow::Borrowed(&s[start..end]), start..end),
+        Some(start) => (Cow::Owned(word), start..end),
+        None => (Cow::Owned(word), from..from),
+    }
+}
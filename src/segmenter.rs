@@ -0,0 +1,90 @@
+//! A ruleset compiled once and applied to any number of inputs.
+//!
+//! The static entry points read their rules from a type, and a type cannot hold anything, so each
+//! call works the rules out again: `Charwalk` builds the punctuation and special character sets
+//! and reduces the rule list to flags, on every input, before reading a character of it. That was
+//! measured at about a sixth of a call. A [`Segmenter`] does that once, when it is made, and then
+//! only walks.
+
+use std::borrow::Cow;
+
+use crate::error::SegmentationError;
+use crate::impls::charwalk::Charwalk;
+use crate::rules::RuleSet;
+use crate::segment::Segment;
+use crate::WordBoundResolverImpl;
+
+/// A ruleset prepared for one backend, reusable and shareable between threads.
+///
+/// For `Charwalk` what is held is the compiled form of the rules the walk reads; for the regex
+/// backends it is their compiled pattern. Either way it is built in [`Segmenter::new`] and only
+/// read afterwards, so a `Segmenter` behind a reference or an `Arc` serves every thread at once.
+///
+/// ```
+/// use word_bounds::segmenter::Segmenter;
+///
+/// let segmenter: Segmenter = Segmenter::default();
+/// for input in ["JSONResponse", "snake_case"] {
+///     println!("{:?}", segmenter.resolve(input));
+/// }
+/// ```
+pub struct Segmenter<I: WordBoundResolverImpl = Charwalk> {
+    rules: RuleSet,
+    prepared: I::Prepared,
+}
+
+impl<I: WordBoundResolverImpl> Segmenter<I> {
+    pub fn new(rules: RuleSet) -> Self {
+        let prepared = I::prepare(&rules);
+        Segmenter { rules, prepared }
+    }
+
+    /// [`new`](Self::new), returning an error where it would panic, on a rule the backend does
+    /// not support or a pattern that does not compile. Rules read from configuration are best
+    /// prepared through this, once, so that a bad ruleset is found before any input is.
+    pub fn try_new(rules: RuleSet) -> Result<Self, SegmentationError> {
+        let prepared = I::try_prepare(&rules)?;
+        Ok(Segmenter { rules, prepared })
+    }
+
+    /// The ruleset this was prepared from.
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    /// The words of `s`, owned and cased as the ruleset says.
+    pub fn resolve(&self, s: &str) -> Vec<String> {
+        let (case, locale) = (self.rules.case_mode, self.rules.locale);
+        self.iter(s).map(|segment| case.apply_in(&segment.text, locale)).collect()
+    }
+
+    /// [`resolve`](Self::resolve), returning an error where the backend would panic matching
+    /// `s`.
+    pub fn try_resolve(&self, s: &str) -> Result<Vec<String>, SegmentationError> {
+        let (case, locale) = (self.rules.case_mode, self.rules.locale);
+        let segments = I::try_iter_prepared(&self.prepared, s)?;
+        Ok(segments.map(|segment| case.apply_in(&segment.text, locale)).collect())
+    }
+
+    /// The words of `s` as slices of it, in the input's own case.
+    pub fn resolve_borrowed<'a>(&self, s: &'a str) -> Vec<Cow<'a, str>> {
+        self.iter(s).map(|segment| segment.text).collect()
+    }
+
+    /// The words of `s` with their byte and char ranges in it.
+    pub fn segments<'a>(&self, s: &'a str) -> Vec<Segment<'a>> {
+        self.iter(s).collect()
+    }
+
+    /// The segments of `s` one at a time.
+    pub fn iter<'a>(&self, s: &'a str) -> I::Iter<'a> {
+        I::iter_prepared(&self.prepared, s)
+    }
+}
+
+impl<I: WordBoundResolverImpl> Default for Segmenter<I> {
+    /// The default rules, prepared.
+    fn default() -> Self {
+        Segmenter::new(RuleSet::default())
+    }
+}
@@ -0,0 +1,77 @@
+//! Acronyms as a target of their own, read the same by every backend.
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::All;
+use word_bounds::rules::ResolverProcessingRule::{BoundStart, Remove};
+use word_bounds::rules::RuleTarget::{Acronym, CaseChangeNonAcronym};
+use word_bounds::rules::RuleSet;
+use word_bounds::segment::SegmentKind;
+
+type Cases = &'static [(&'static str, &'static [&'static str])];
+
+/// Each ruleset with inputs and the words every backend resolves them to, past the default rules
+/// the shared table covers.
+fn acronyms() -> Vec<(RuleSet, Cases)> {
+    let mut removed = RuleSet::default();
+    removed.resolution_pass_rules.push(Remove(Acronym, All));
+    // bounded by the acronym rules alone, with no case change rule to split at
+    let mut alone = RuleSet::default();
+    alone.resolution_pass_rules.retain(|rule| rule != &BoundStart(CaseChangeNonAcronym));
+    alone.resolution_pass_rules.push(BoundStart(Acronym));
+    vec![
+        (
+            RuleSet { acronym_min_len: 3, ..RuleSet::default() },
+            &[("IDs_and_URLs", &["i", "ds", "and", "urls"])],
+        ),
+        (removed, &[("parseURLsFast", &["parse", "fast"])]),
+        (alone, &[("parseURLsFast", &["parse", "urls", "fast"])]),
+    ]
+}
+
+#[test]
+fn charwalk_reads_acronyms() {
+    for (rules, cases) in acronyms() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Charwalk>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_reads_acronyms() {
+    for (rules, cases) in acronyms() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<FancyRegex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_reads_acronyms() {
+    for (rules, cases) in acronyms() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Regex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn a_plural_acronym_is_tagged_as_one() {
+    let segments = WordBoundResolver::<Charwalk>::segments("parseURLs");
+    let kinds: Vec<_> = segments.iter().map(|segment| segment.kind).collect();
+    assert_eq!(kinds, [SegmentKind::Word, SegmentKind::Acronym]);
+
+    let longer = RuleSet { acronym_min_len: 4, ..RuleSet::default() };
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("parseURL", &longer);
+    assert_eq!(segments[1].kind, SegmentKind::Word);
+}
@@ -0,0 +1,109 @@
+//! Tokens glued onto the word before or after them, the same for every backend.
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::Direction::{Auto, Next, Previous};
+use word_bounds::rules::ResolverProcessingRule::{Attach, BoundEnd, BoundStart};
+use word_bounds::rules::RuleTarget::{Char, Numerics};
+use word_bounds::rules::{ResolverProcessingRule, RuleSet};
+use word_bounds::segment::SegmentKind;
+
+/// The default rules, with whatever bounds the attached characters taken out.
+fn with(attach: Vec<ResolverProcessingRule>) -> RuleSet {
+    let mut rules = RuleSet::default();
+    rules.resolution_pass_rules.retain(|rule| {
+        !matches!(rule, BoundStart(target) | BoundEnd(target)
+            if attach.iter().any(|attached| attached.target() == Some(target)))
+    });
+    rules.resolution_pass_rules.extend(attach);
+    rules
+}
+
+type Cases = &'static [(&'static str, &'static [&'static str])];
+
+/// Each ruleset with inputs and the words every backend resolves them to.
+fn attached() -> Vec<(RuleSet, Cases)> {
+    vec![
+        (
+            with(vec![Attach(Char('#'), Next), Attach(Char('@'), Next)]),
+            &[
+                ("hashtag#rust", &["hashtag", "#rust"]),
+                ("ping @user", &["ping", "@user"]),
+                // nothing to go to
+                ("trailing#", &["trailing", "#"]),
+            ],
+        ),
+        (
+            with(vec![Attach(Char('%'), Previous)]),
+            &[
+                ("50% off", &["50%", "off"]),
+                // nothing to go to
+                ("%off", &["%", "off"]),
+            ],
+        ),
+        (
+            with(vec![Attach(Numerics, Auto)]),
+            &[
+                ("v2 release", &["v2", "release"]),
+                ("enable 2FA", &["enable", "2fa"]),
+                // touching nothing
+                ("page 12", &["page", "12"]),
+            ],
+        ),
+        (
+            with(vec![Attach(Char('%'), Auto), Attach(Char('#'), Auto)]),
+            &[
+                ("50%off", &["50%", "off"]),
+                ("hashtag#rust", &["hashtag", "#rust"]),
+                ("ends with #", &["ends", "with", "#"]),
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn charwalk_attaches() {
+    for (rules, cases) in attached() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Charwalk>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_attaches() {
+    for (rules, cases) in attached() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<FancyRegex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_attaches() {
+    for (rules, cases) in attached() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Regex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn an_attached_word_spans_its_token() {
+    let rules = with(vec![Attach(Char('#'), Next), Attach(Char('%'), Previous)]);
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("up 50% #rust", &rules);
+    let spans: Vec<_> =
+        segments.iter().map(|segment| (segment.as_str(), segment.byte_range.clone())).collect();
+    assert_eq!(spans, [("up", 0..2), ("50%", 3..6), ("#rust", 7..12)]);
+    assert_eq!(segments[1].kind, SegmentKind::Suffixed('%'));
+    assert_eq!(segments[2].kind, SegmentKind::Prefixed('#'));
+}
@@ -2,8 +2,9 @@
 //!
 //! The crate offers "varying implementations to choose from", so what they do differently
 //! is the thing a chooser needs to know, and it was recorded as one sentence: "regex does
-//! not segment punctuation runs; charwalk does". That is true and too coarse to act on.
-//! The two backends differ from charwalk in different ways, and from each other.
+//! not segment punctuation runs; charwalk does". That was true and too coarse to act on.
+//! The two backends differed from charwalk in different ways, and from each other, and
+//! both read runs as charwalk does now.
 //!
 //! These cases are the difference, written down. Every one was produced by running the
 //! three against the same input, not from reading the code.
@@ -18,7 +19,11 @@ use word_bounds::impls::fancy_regex::FancyRegex;
 #[cfg(feature = "use_regex")]
 use word_bounds::impls::regex::Regex;
 use word_bounds::resolver::WordBoundResolver;
-use word_bounds::rules::DefaultRules;
+use word_bounds::rules::RemoveMode::Middle;
+use word_bounds::rules::ResolverProcessingRule::Remove;
+use word_bounds::rules::RuleTarget::Char;
+use word_bounds::rules::Scope::SingleWord;
+use word_bounds::rules::{DefaultRules, ResolverProcessingRule, ResolverRules};
 
 fn charwalk(input: &str) -> Vec<String> {
     WordBoundResolver::<Charwalk, DefaultRules>::resolve(input)
@@ -36,15 +41,25 @@ const AGREED: &[&str] = &[
     "a.b",
     "a.,b",
     "a!?!b",
+    // punctuation and symbols outside ASCII, read by their category, and letters in any script
+    "foo—bar",
+    "a…b",
+    "«Bonjour», dit-il",
+    "a、b。",
+    "¿Qué?",
+    "a·b",
+    "5€ off",
+    "ThisIsÜberCool",
+    "日本語テキスト",
 ];
 
 /// A run is the same character repeated, and this is what says so.
 ///
-/// It matters because it decides whether the `regex` backend could ever implement the
-/// rule. Matching "the same character again" needs a backreference, which the `regex`
-/// crate does not have by design; matching "more punctuation" would not. `a.,b` and
-/// `a!?!b` are mixed punctuation and charwalk drops them, so the rule is the first thing
-/// and the `regex` backend genuinely cannot express it.
+/// It matters because it decides how the `regex` backend implements the rule. Matching
+/// "the same character again" needs a backreference, which the `regex` crate does not have
+/// by design; matching "more punctuation" would not. `a.,b` and `a!?!b` are mixed
+/// punctuation and charwalk drops them, so the rule is the first thing, and the `regex`
+/// backend puts its runs back together after matching rather than in its pattern.
 #[test]
 fn a_run_is_the_same_character_repeated_not_any_punctuation() {
     assert_eq!(charwalk("a...b"), ["a", "...", "b"], "repeated: kept as a run");
@@ -70,18 +85,20 @@ mod plain_regex {
     }
 
     #[test]
-    fn drops_an_interior_run() {
-        // Charwalk gives ["a", "...", "b"].
-        assert_eq!(under_test("a...b"), ["a", "b"]);
+    fn keeps_an_interior_run_whole() {
+        // It used to drop one. Runs are put back together after matching now, since the
+        // pattern cannot say "the same character again", and read the way charwalk reads them.
+        for input in ["a...b", "a.,b", "x,,,.y"] {
+            assert_eq!(under_test(input), charwalk(input), "disagreed on {input:?}");
+        }
+        assert_eq!(under_test("x,,,.y"), ["x", ",,,", "y"], "a run ends where the character does");
     }
 
     #[test]
-    fn collapses_a_leading_or_trailing_run_to_one_character() {
-        // The sharper half of the divergence, and the half the one-sentence note missed:
-        // this backend does not merely fail to split a run, it loses all but one character
-        // of it. Charwalk gives ["...", "leading"] and ["trailing", "..."].
-        assert_eq!(under_test("...leading"), [".", "leading"]);
-        assert_eq!(under_test("trailing..."), ["trailing", "."]);
+    fn keeps_a_leading_or_trailing_run_whole() {
+        // It used to keep one character of it, which was the sharper half of the divergence.
+        assert_eq!(under_test("...leading"), ["...", "leading"]);
+        assert_eq!(under_test("trailing..."), ["trailing", "..."]);
     }
 }
 
@@ -102,21 +119,23 @@ mod fancy {
 
     #[test]
     fn keeps_a_leading_or_trailing_run_whole() {
-        // Where the plain regex backend loses all but one character, this one does not, so
-        // the two are not the same defect and do not have the same fix.
+        // Where the plain regex backend used to lose all but one character, this one did
+        // not, so the two were not the same defect and did not have the same fix.
         assert_eq!(under_test("...leading"), ["...", "leading"]);
         assert_eq!(under_test("trailing..."), ["trailing", "..."]);
     }
 
     #[test]
-    fn drops_an_interior_run() {
-        // The whole of what is left between this backend and charwalk. It is a removal
-        // rather than a missing split: the run does not appear unsplit, it does not appear
-        // at all, so the fix is in the post-pass and not in the pattern.
-        assert_eq!(under_test("a...b"), ["a", "b"]);
+    fn keeps_an_interior_run_whole() {
+        // It used to drop one, as a removal rather than a missing split. The pattern matches
+        // a run with a backreference now, ahead of the punctuation it would otherwise be
+        // removed as, along with the lone punctuation either side of it that is.
+        for input in ["a...b", "x,,,.y", "x.,,,y", "a!?!b", "a-.-b"] {
+            assert_eq!(under_test(input), charwalk(input), "disagreed on {input:?}");
+        }
         assert_eq!(
             under_test("...ellipses... could ... be hard..."),
-            ["...", "ellipses", "could", "...", "be", "hard", "..."],
+            ["...", "ellipses", "...", "could", "...", "be", "hard", "..."],
         );
     }
 }
@@ -156,3 +175,41 @@ fn the_shared_pattern_survives_many_threads_arriving_together() {
         assert_eq!(outcome, &expected, "a thread disagreed with the rest");
     }
 }
+
+/// The default rules, with an apostrophe inside a word taken out of it rather than splitting it.
+struct Contracting;
+
+impl ResolverRules for Contracting {
+    fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+        let mut rules = DefaultRules::resolution_pass_rules();
+        rules.push(Remove(Char('\''), Middle(SingleWord)));
+        rules
+    }
+}
+
+/// Two rulesets through one backend, one after the other.
+///
+/// The shared pattern was one per backend, whatever the ruleset, so the first ruleset a process
+/// resolved with compiled the pattern every later one was handed: after `Contracting`, the
+/// default rules kept `don't` whole too.
+#[test]
+fn each_ruleset_has_a_shared_pattern_of_its_own() {
+    let contracted = ["i", "dont", "know"];
+    let split = ["i", "don", "'", "t", "know"];
+    let resolve = WordBoundResolver::<Charwalk<Contracting>, Contracting>::resolve;
+    assert_eq!(resolve("I don't know"), contracted);
+    assert_eq!(charwalk("I don't know"), split);
+
+    #[cfg(feature = "use_regex")]
+    {
+        let resolve = WordBoundResolver::<Regex<Contracting>, Contracting>::resolve;
+        assert_eq!(resolve("I don't know"), contracted);
+        assert_eq!(WordBoundResolver::<Regex, DefaultRules>::resolve("I don't know"), split);
+    }
+    #[cfg(feature = "use_fancy_regex")]
+    {
+        let resolve = WordBoundResolver::<FancyRegex<Contracting>, Contracting>::resolve;
+        assert_eq!(resolve("I don't know"), contracted);
+        assert_eq!(WordBoundResolver::<FancyRegex, DefaultRules>::resolve("I don't know"), split);
+    }
+}
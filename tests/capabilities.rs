@@ -0,0 +1,112 @@
+//! What each backend says it reads of a ruleset, checked against what it does with it.
+
+use word_bounds::capabilities::{validate, Shortfall};
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::All;
+use word_bounds::rules::ResolverProcessingRule::{BoundStart, Remove};
+use word_bounds::rules::RuleTarget::Word;
+use word_bounds::rules::{ResolverProcessingRule, RuleSet, WordPredicate};
+use word_bounds::WordBoundResolverImpl;
+
+fn with(rules: &[ResolverProcessingRule]) -> RuleSet {
+    let mut set = RuleSet::default();
+    set.resolution_pass_rules.extend_from_slice(rules);
+    set
+}
+
+#[test]
+fn every_backend_reads_the_default_rules_in_full() {
+    assert_eq!(validate::<Charwalk>(&RuleSet::default()), []);
+    #[cfg(feature = "use_regex")]
+    assert_eq!(validate::<Regex>(&RuleSet::default()), []);
+    #[cfg(feature = "use_fancy_regex")]
+    assert_eq!(validate::<FancyRegex>(&RuleSet::default()), []);
+}
+
+#[test]
+fn charwalk_passes_over_whole_words_in_resolution() {
+    let rules = with(&[
+        Remove(Word(WordPredicate::Any), All),
+        BoundStart(Word(WordPredicate::Any)),
+    ]);
+    let findings = validate::<Charwalk>(&rules);
+    assert!(findings.iter().all(|found| found.shortfall == Shortfall::Ignored));
+    assert_eq!(findings.len(), 2);
+    // and it says so rather than refusing them, so it resolves as though they were not there
+    assert_eq!(
+        WordBoundResolver::<Charwalk>::resolve_with_rules("CamelCase", &rules),
+        ["camel", "case"]
+    );
+    assert!(<Charwalk>::capabilities().lazy);
+}
+
+#[test]
+fn charwalk_is_lazy_unless_a_pass_around_it_reads_all_of_the_input() {
+    use word_bounds::rules::Direction::Next;
+    use word_bounds::rules::RemoveMode::Prepended;
+    use word_bounds::rules::RuleTarget::{Char, String};
+    use word_bounds::rules::Scope::FullInput;
+
+    let lazy = |rules: &RuleSet| <Charwalk>::capabilities_for(rules).lazy;
+    assert!(lazy(&RuleSet::default()));
+    assert!(lazy(&with(&[BoundStart(String("::".into()))])));
+    assert!(!lazy(&with(&[Remove(Char('_'), Prepended(FullInput))])));
+    assert!(!lazy(&with(&[ResolverProcessingRule::Attach(Char('#'), Next)])));
+    let mut pre_pass = RuleSet::default();
+    pre_pass.pre_pass_rules.push(Remove(Char('-'), All));
+    assert!(!lazy(&pre_pass));
+    let mut post_pass = RuleSet::default();
+    post_pass.post_pass_rules.push(Remove(Char('-'), All));
+    assert!(!lazy(&post_pass));
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_reports_what_it_refuses_and_what_it_reads_otherwise() {
+    use word_bounds::capabilities::Finding;
+    use word_bounds::rules::RemoveMode::Middle;
+    use word_bounds::rules::ResolverProcessingRule::BoundEnd;
+    use word_bounds::rules::RuleTarget::{Char, Numerics, PunctSpecialCharRun};
+    use word_bounds::rules::Scope::FullInput;
+
+    let rules = with(&[Remove(Numerics, All), Remove(Char('x'), Middle(FullInput))]);
+    assert_eq!(
+        validate::<Regex>(&rules),
+        [
+            Finding { rule: Remove(Numerics, All), shortfall: Shortfall::Unsupported },
+            Finding {
+                rule: Remove(Char('x'), Middle(FullInput)),
+                shortfall: Shortfall::Approximated(
+                    "every occurrence is removed, whatever the mode"
+                ),
+            },
+        ]
+    );
+    // what it refuses is what it fails on
+    assert!(WordBoundResolver::<Regex>::try_resolve_with_rules("a1", &rules).is_err());
+
+    let mut runs = RuleSet::default();
+    runs.resolution_pass_rules.retain(|rule| rule != &BoundEnd(PunctSpecialCharRun));
+    let findings = validate::<Regex>(&runs);
+    assert!(matches!(findings[..], [Finding { shortfall: Shortfall::Approximated(_), .. }]));
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_ignores_a_case_change_bounded_at_its_end() {
+    use word_bounds::capabilities::Finding;
+    use word_bounds::rules::ResolverProcessingRule::BoundEnd;
+    use word_bounds::rules::RuleTarget::CaseChangeNonAcronym;
+
+    let rules = with(&[BoundEnd(CaseChangeNonAcronym)]);
+    assert_eq!(
+        validate::<FancyRegex>(&rules),
+        [Finding { rule: BoundEnd(CaseChangeNonAcronym), shortfall: Shortfall::Ignored }]
+    );
+    assert!(!<FancyRegex>::capabilities().lazy);
+}
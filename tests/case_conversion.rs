@@ -0,0 +1,145 @@
+use word_bounds::case::{Case, StrExt};
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+use word_bounds::rules::DefaultRules;
+
+#[test]
+fn every_case_from_one_input() {
+    let input = "JSONResponse";
+    assert_eq!(input.to_snake_case(), "json_response");
+    assert_eq!(input.to_screaming_snake_case(), "JSON_RESPONSE");
+    assert_eq!(input.to_kebab_case(), "json-response");
+    assert_eq!(input.to_camel_case(), "jsonResponse");
+    assert_eq!(input.to_pascal_case(), "JsonResponse");
+    assert_eq!(input.to_train_case(), "Json-Response");
+    assert_eq!(input.to_title_case(), "Json Response");
+    assert_eq!(input.to_dot_case(), "json.response");
+    assert_eq!(input.to_path_case(), "json/response");
+}
+
+#[test]
+fn round_trips_between_cases() {
+    // Not `Path`: `/` is not punctuation to the default rules but a special character, which is
+    // kept as a token of its own, so reading a path back needs rules that count it as a separator.
+    let cases = [
+        Case::Snake,
+        Case::ScreamingSnake,
+        Case::Kebab,
+        Case::Camel,
+        Case::Pascal,
+        Case::Train,
+        Case::Title,
+        Case::Dot,
+    ];
+    for from in cases {
+        let there = "some_http_server_v2".to_case(from);
+        assert_eq!(there.to_snake_case(), "some_http_server_v_2", "through {from:?}: {there:?}");
+    }
+}
+
+#[test]
+fn an_acronym_stays_one_word() {
+    assert_eq!(
+        "thisExampleHasIDELikeACRONYMS".to_snake_case(),
+        "this_example_has_ide_like_acronyms"
+    );
+    assert_eq!("HTTPServer".to_kebab_case(), "http-server");
+    assert_eq!("someHTML".to_pascal_case(), "SomeHtml");
+}
+
+#[test]
+fn separators_kept_at_the_ends_do_not_survive() {
+    assert_eq!("_PrependedUnderscore".to_snake_case(), "prepended_underscore");
+    assert_eq!("ellipses...".to_camel_case(), "ellipses");
+}
+
+#[test]
+fn numbers_are_their_own_words() {
+    assert_eq!("WordWithNumbers123".to_snake_case(), "word_with_numbers_123");
+    assert_eq!("word_with_numbers_123".to_camel_case(), "wordWithNumbers123");
+}
+
+#[test]
+fn titlecase_letters_and_final_sigma_are_written_as_they_are() {
+    assert_eq!("ǆungla_bar".to_pascal_case(), "ǅunglaBar");
+    assert_eq!("ᾠδή και".to_title_case(), "ᾨδή Και");
+    assert_eq!("ΟΔΟΣ_ΔΡΟΜΟΣ".to_snake_case(), "οδος_δρομος");
+    assert_eq!("οδος_δρομος".to_camel_case(), "οδοςΔρομος");
+}
+
+#[test]
+fn joining_words_already_in_hand() {
+    assert_eq!(Case::Pascal.join(["json", "response"]), "JsonResponse");
+    assert_eq!(Case::ScreamingSnake.join(Vec::<String>::new()), "");
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn another_backend_segments_the_same_way() {
+    assert_eq!(
+        Case::Snake.convert_with::<FancyRegex, DefaultRules>("thisExampleHasIDELikeACRONYMS"),
+        "this_example_has_ide_like_acronyms"
+    );
+}
+
+#[test]
+fn the_default_backend_is_the_one_rules_choose_by_default() {
+    assert_eq!(
+        Case::Snake.convert_with::<word_bounds::impls::charwalk::Charwalk, DefaultRules>("a-b"),
+        Case::Snake.convert("a-b")
+    );
+}
+
+mod acronyms {
+    use word_bounds::case::AcronymStyle::{Listed, Uppercase};
+    use word_bounds::case::{AcronymStyle, Case, StrExt};
+
+    #[test]
+    fn capitalised_like_any_word_by_default() {
+        assert_eq!(AcronymStyle::default(), AcronymStyle::Capitalise);
+        assert_eq!("JSONResponse".to_pascal_case(), "JsonResponse");
+    }
+
+    #[test]
+    fn kept_in_upper_case_where_detected() {
+        let pascal = Case::Pascal.with_acronyms(Uppercase);
+        assert_eq!("JSONResponse".to_case(pascal.clone()), "JSONResponse");
+        assert_eq!("parseHTMLDocument".to_case(pascal.clone()), "ParseHTMLDocument");
+        assert_eq!("found_URLs".to_case(pascal), "FoundURLs");
+        // nothing in the input says `json` was one
+        assert_eq!("json_response".to_case(Case::Pascal.with_acronyms(Uppercase)), "JsonResponse");
+    }
+
+    #[test]
+    fn a_leading_camel_word_stays_lower() {
+        assert_eq!("JSONResponse".to_case(Case::Camel.with_acronyms(Uppercase)), "jsonResponse");
+        assert_eq!("ResponseJSON".to_case(Case::Camel.with_acronyms(Uppercase)), "responseJSON");
+    }
+
+    #[test]
+    fn the_list_decides_and_spells() {
+        let listed = Listed(vec!["ID".into(), "HTTP".into(), "URL".into(), "iOS".into()]);
+        let pascal = Case::Pascal.with_acronyms(listed);
+        assert_eq!("user_id".to_case(pascal.clone()), "UserID");
+        assert_eq!("http_url".to_case(pascal.clone()), "HTTPURL");
+        assert_eq!("ios_app".to_case(pascal.clone()), "iOSApp");
+        // detected, but not listed
+        assert_eq!("JSONResponse".to_case(pascal), "JsonResponse");
+    }
+
+    #[test]
+    fn cases_that_do_not_capitalise_are_untouched() {
+        let snake = Case::Snake.with_acronyms(Uppercase);
+        assert_eq!("JSONResponse".to_case(snake), "json_response");
+        let screaming = Case::ScreamingSnake.with_acronyms(Listed(vec!["iOS".into()]));
+        assert_eq!("iosApp".to_case(screaming), "IOS_APP");
+    }
+
+    #[test]
+    fn words_joined_without_segments_are_judged_by_their_case() {
+        let pascal = Case::Pascal.with_acronyms(Uppercase);
+        assert_eq!(pascal.join(["JSON", "response"]), "JSONResponse");
+        assert_eq!(pascal.join(["json", "response"]), "JsonResponse");
+        assert_eq!(pascal.join(["A", "list"]), "AList");
+    }
+}
@@ -0,0 +1,56 @@
+//! Punctuation and special characters outside ASCII, read by their general category.
+
+use word_bounds::categories::GeneralCategory;
+use word_bounds::impls::charwalk::Charwalk;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::ResolverProcessingRule::BoundStart;
+use word_bounds::rules::RuleSet;
+use word_bounds::rules::RuleTarget::Char;
+
+#[test]
+fn reads_a_character_by_its_category() {
+    assert_eq!(GeneralCategory::of('—'), Some(GeneralCategory::Pd));
+    assert_eq!(GeneralCategory::of('«'), Some(GeneralCategory::Pi));
+    assert_eq!(GeneralCategory::of('、'), Some(GeneralCategory::Po));
+    assert_eq!(GeneralCategory::of('€'), Some(GeneralCategory::Sc));
+    assert_eq!(GeneralCategory::of('🎉'), Some(GeneralCategory::So));
+    assert_eq!(GeneralCategory::of('#'), Some(GeneralCategory::Po));
+    assert_eq!(GeneralCategory::of('ü'), None);
+    assert_eq!(GeneralCategory::of('日'), None);
+    assert_eq!(GeneralCategory::Pd.abbreviation(), "Pd");
+}
+
+#[test]
+fn keeps_what_modifies_a_symbol_with_it() {
+    // charwalk alone: the regex backends split these into their parts
+    let resolve = WordBoundResolver::<Charwalk>::resolve;
+    assert_eq!(resolve("ok👍🏽ok"), ["ok", "👍🏽", "ok"]);
+    assert_eq!(resolve("🇫🇮🇸🇪"), ["🇫🇮", "🇸🇪"]);
+    assert_eq!(resolve("a👨\u{200D}👩\u{200D}👧b"), ["a", "👨\u{200D}👩\u{200D}👧", "b"]);
+}
+
+#[test]
+fn reads_the_categories_the_rules_choose() {
+    // none, and what lies outside ASCII is letters again
+    let rules = RuleSet {
+        punct_categories: vec![],
+        special_categories: vec![],
+        ..RuleSet::default()
+    };
+    let words = WordBoundResolver::<Charwalk>::resolve_with_rules("foo—bar «x»", &rules);
+    assert_eq!(words, ["foo—bar", "«x»"]);
+
+    // quotes as punctuation rather than special characters
+    let rules = RuleSet {
+        punct_categories: vec![GeneralCategory::Pd, GeneralCategory::Pi, GeneralCategory::Pf],
+        ..RuleSet::default()
+    };
+    let words = WordBoundResolver::<Charwalk>::resolve_with_rules("a«b»c", &rules);
+    assert_eq!(words, ["a", "b", "c"]);
+
+    // and a character a rule names is read by the rule rather than by its category
+    let mut rules = RuleSet::default();
+    rules.resolution_pass_rules.push(BoundStart(Char('§')));
+    let words = WordBoundResolver::<Charwalk>::resolve_with_rules("see§ref", &rules);
+    assert_eq!(words, ["see", "§ref"]);
+}
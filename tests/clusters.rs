@@ -0,0 +1,43 @@
+//! The walk over grapheme clusters, so that no word is cut inside what reads as one character.
+
+#![cfg(feature = "enhanced_accuracy")]
+
+use word_bounds::impls::charwalk::Charwalk;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::All;
+use word_bounds::rules::ResolverProcessingRule::{BoundEnd, Remove};
+use word_bounds::rules::RuleTarget::Char;
+use word_bounds::rules::RuleSet;
+
+#[test]
+fn a_mark_stays_with_the_character_it_marks() {
+    let resolve = WordBoundResolver::<Charwalk>::resolve;
+    // bounded after the digit and after the special character, not between them and the accent
+    assert_eq!(resolve("1\u{301}a"), ["1\u{301}", "a"]);
+    assert_eq!(resolve("$\u{301}x"), ["$\u{301}", "x"]);
+    // and the letter under the accent is what a case change is read off
+    assert_eq!(resolve("cafe\u{301}Noir"), ["cafe\u{301}", "noir"]);
+
+    let segments = WordBoundResolver::<Charwalk>::segments("1\u{301}a");
+    assert_eq!(segments[0].byte_range, 0..3);
+    assert_eq!(segments[0].char_range, 0..2);
+    assert_eq!(segments[1].char_range, 2..3);
+}
+
+#[test]
+fn a_rule_cannot_end_a_word_inside_a_cluster() {
+    let mut rules = RuleSet::default();
+    rules.resolution_pass_rules.push(BoundEnd(Char('\u{200D}')));
+    let family = "👨\u{200D}👩\u{200D}👧";
+    assert_eq!(WordBoundResolver::<Charwalk>::resolve_with_rules(family, &rules), [family]);
+}
+
+#[test]
+fn the_passes_take_a_cluster_whole() {
+    let mut rules = RuleSet::default();
+    rules.post_pass_rules.push(Remove(Char('e'), All));
+    assert_eq!(
+        WordBoundResolver::<Charwalk>::resolve_with_rules("cafe\u{301} bee", &rules),
+        ["caf", "b"]
+    );
+}
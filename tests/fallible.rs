@@ -0,0 +1,93 @@
+//! The `try_` entry points, which return what the others would panic with.
+
+// Charwalk reads every rule it is given, so what can go wrong is in the regex backends, which the
+// default selection does not compile.
+#![cfg(any(feature = "use_regex", feature = "use_fancy_regex"))]
+
+use word_bounds::error::SegmentationError;
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::All;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::rules::ResolverProcessingRule::BoundStart;
+use word_bounds::rules::ResolverProcessingRule::Remove;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::rules::RuleTarget::Char;
+use word_bounds::rules::RuleTarget::Numerics;
+#[cfg(feature = "use_regex")]
+use word_bounds::rules::RuleTarget::Word;
+#[cfg(feature = "use_regex")]
+use word_bounds::rules::WordPredicate;
+use word_bounds::rules::{ResolverProcessingRule, RuleSet};
+#[cfg(feature = "use_regex")]
+use word_bounds::segmenter::Segmenter;
+
+fn with(rule: ResolverProcessingRule) -> RuleSet {
+    let mut rules = RuleSet::default();
+    rules.resolution_pass_rules.push(rule);
+    rules
+}
+
+#[test]
+fn a_supported_ruleset_resolves_as_it_would_otherwise() {
+    let input = "This_is_SomeRandom_Text-to-split2";
+    let expected = WordBoundResolver::<Charwalk>::resolve(input);
+    assert_eq!(WordBoundResolver::<Charwalk>::try_resolve(input), Ok(expected.clone()));
+    #[cfg(feature = "use_regex")]
+    assert_eq!(WordBoundResolver::<Regex>::try_resolve(input), Ok(expected.clone()));
+    #[cfg(feature = "use_fancy_regex")]
+    assert_eq!(WordBoundResolver::<FancyRegex>::try_resolve(input), Ok(expected));
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_names_the_rule_it_does_not_support() {
+    let rule = Remove(Numerics, All);
+    let rules = with(rule.clone());
+    let error = WordBoundResolver::<Regex>::try_resolve_with_rules("abc123", &rules).unwrap_err();
+    assert_eq!(error, SegmentationError::UnsupportedRule { backend: "regex", rule });
+    assert_eq!(
+        error.to_string(),
+        "the regex backend does not support the rule Remove(Numerics, All)"
+    );
+
+    let rule = Remove(Word(WordPredicate::Any), All);
+    assert!(matches!(
+        Segmenter::<Regex>::try_new(with(rule)),
+        Err(SegmentationError::UnsupportedRule { backend: "regex", .. })
+    ));
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_names_the_rule_it_does_not_support() {
+    let rule = Remove(Numerics, All);
+    assert_eq!(
+        WordBoundResolver::<FancyRegex>::try_resolve_with_rules("abc123", &with(rule.clone())),
+        Err(SegmentationError::UnsupportedRule { backend: "fancy_regex", rule })
+    );
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn a_pattern_that_does_not_compile_is_an_error() {
+    // the character goes into the pattern as it is, and an open parenthesis opens a group
+    let rules = with(BoundStart(Char('(')));
+    assert!(matches!(
+        WordBoundResolver::<FancyRegex>::try_resolve_with_rules("f(x)", &rules),
+        Err(SegmentationError::InvalidPattern { backend: "fancy_regex", .. })
+    ));
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_bounds_a_character_at_the_end_of_the_input() {
+    // it used to look for what follows the character by unwrapping, and panic on nothing
+    let words = WordBoundResolver::<Regex>::try_resolve("tag#");
+    assert_eq!(words, Ok(vec!["tag".into(), "#".into()]));
+    assert_eq!(WordBoundResolver::<Regex>::resolve("a#b"), ["a", "#b"]);
+}
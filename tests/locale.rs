@@ -0,0 +1,64 @@
+//! Casing as a language has it, for the languages whose casing is not Unicode's default.
+
+use word_bounds::impls::charwalk::Charwalk;
+use word_bounds::locale::Locale;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::{CaseMode, RuleSet};
+
+fn in_locale(locale: Locale, case_mode: CaseMode) -> RuleSet {
+    RuleSet {
+        locale: Some(locale),
+        case_mode,
+        ..RuleSet::default()
+    }
+}
+
+#[test]
+fn reads_a_locale_off_a_language_tag() {
+    assert_eq!(Locale::from_tag("tr"), Some(Locale::Turkish));
+    assert_eq!(Locale::from_tag("tr-TR"), Some(Locale::Turkish));
+    assert_eq!(Locale::from_tag("AZ_Latn_AZ"), Some(Locale::Azerbaijani));
+    assert_eq!(Locale::from_tag("lt-LT"), Some(Locale::Lithuanian));
+    assert_eq!(Locale::from_tag("en-US"), None);
+    assert_eq!(Locale::from_tag(""), None);
+}
+
+#[test]
+fn lowercases_the_dotted_and_dotless_i_apart_in_turkish() {
+    let rules = in_locale(Locale::Turkish, CaseMode::Lower);
+    let resolve = |s| WordBoundResolver::<Charwalk>::resolve_with_rules(s, &rules);
+    assert_eq!(resolve("İstanbul ILIK"), ["istanbul", "ılık"]);
+    assert_eq!(resolve("DİYARBAKIR"), ["diyarbakır"]);
+    // a capital `I` with its dot written apart is the dotted one
+    assert_eq!(resolve("I\u{307}zmir"), ["izmir"]);
+    // and without a locale, Unicode's default
+    let words = WordBoundResolver::<Charwalk>::resolve("İstanbul ILIK");
+    assert_eq!(words, ["i\u{307}stanbul", "ilik"]);
+
+    assert_eq!(Locale::Azerbaijani.to_lowercase("BAKI"), "bakı");
+}
+
+#[test]
+fn uppercases_and_folds_the_turkic_i() {
+    assert_eq!(Locale::Turkish.to_uppercase("istanbul ılık"), "İSTANBUL ILIK");
+    assert_eq!(CaseMode::Upper.apply_in("iğne", Some(Locale::Turkish)), "İĞNE");
+    assert_eq!(CaseMode::Fold.apply_in("IŞIK İşık", Some(Locale::Turkish)), "ışık işık");
+    // what is not one of the pairs folds as it does anywhere
+    assert_eq!(CaseMode::Fold.apply_in("Straße", Some(Locale::Turkish)), "strasse");
+    assert_eq!(CaseMode::Fold.apply_in("ΣΟΦΟΣ", Some(Locale::Azerbaijani)), "σοφοσ");
+}
+
+#[test]
+fn keeps_the_dot_of_an_accented_i_in_lithuanian() {
+    let lower = |s| Locale::Lithuanian.to_lowercase(s);
+    assert_eq!(lower("Ì"), "i\u{307}\u{300}");
+    assert_eq!(lower("Í"), "i\u{307}\u{301}");
+    assert_eq!(lower("Ĩ"), "i\u{307}\u{303}");
+    assert_eq!(lower("I\u{303}"), "i\u{307}\u{303}");
+    assert_eq!(lower("Į\u{301}"), "į\u{307}\u{301}");
+    // without an accent there is no dot to keep
+    assert_eq!(lower("JIS"), "jis");
+    // and it goes again in upper case
+    assert_eq!(Locale::Lithuanian.to_uppercase("i\u{307}\u{300}"), "I\u{300}");
+    assert_eq!(Locale::Lithuanian.to_uppercase("ji\u{307}s"), "JIS");
+}
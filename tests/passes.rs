@@ -0,0 +1,156 @@
+//! The passes either side of resolution: `pre_pass_rules` over the whole input, `post_pass_rules`
+//! over the words, the same for every backend.
+
+use std::borrow::Cow;
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::{All, Appended, Ends, Middle, Prepended};
+use word_bounds::rules::ResolverProcessingRule::{BoundStart, Remove};
+use word_bounds::rules::RuleTarget::{CaseChangeNonAcronym, Char, PunctSpecialChar};
+use word_bounds::rules::Scope::{FullInput, SingleWord};
+use word_bounds::rules::{DefaultRules, ResolverProcessingRule, ResolverRules, RuleSet};
+use word_bounds::segmenter::Segmenter;
+
+fn with_pre(rules: Vec<ResolverProcessingRule>) -> RuleSet {
+    RuleSet {
+        pre_pass_rules: rules,
+        ..RuleSet::default()
+    }
+}
+
+fn with_post(rules: Vec<ResolverProcessingRule>) -> RuleSet {
+    RuleSet {
+        post_pass_rules: rules,
+        ..RuleSet::default()
+    }
+}
+
+type Cases = &'static [(&'static str, &'static [&'static str])];
+
+/// Each ruleset with inputs and the words every backend resolves them to.
+fn passes() -> Vec<(RuleSet, Cases)> {
+    vec![
+        // without passes, the default rules keep punctuation at the ends of the input
+        (RuleSet::default(), &[("_private_name", &["_", "private", "name"])]),
+        (
+            with_pre(vec![Remove(Char('_'), Prepended(FullInput))]),
+            &[("_private_name", &["private", "name"]), ("__init", &["init"])],
+        ),
+        (with_pre(vec![Remove(Char('_'), Appended(FullInput))]), &[("type_", &["type"])]),
+        (with_pre(vec![Remove(Char('\''), All)]), &[("don't stop", &["dont", "stop"])]),
+        (
+            with_post(vec![Remove(PunctSpecialChar, All)]),
+            &[("_private_name_", &["private", "name"])],
+        ),
+        (
+            with_post(vec![Remove(Char('x'), Prepended(FullInput))]),
+            &[("xylo-xenon", &["ylo", "xenon"])],
+        ),
+        (
+            with_post(vec![Remove(Char('x'), Ends(SingleWord))]),
+            &[("xylo-xenonx", &["ylo", "enon"])],
+        ),
+        (
+            with_post(vec![Remove(Char('o'), Middle(SingleWord))]),
+            &[("oboe-door", &["obe", "dr"])],
+        ),
+    ]
+}
+
+#[test]
+fn charwalk_runs_the_passes() {
+    for (rules, cases) in passes() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Charwalk>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_runs_the_passes() {
+    for (rules, cases) in passes() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<FancyRegex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_runs_the_passes() {
+    for (rules, cases) in passes() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Regex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn a_post_pass_bound_splits_words_resolution_left_whole() {
+    let mut whole = RuleSet::default();
+    whole.resolution_pass_rules.retain(|rule| rule != &BoundStart(CaseChangeNonAcronym));
+    assert_eq!(WordBoundResolver::<Charwalk>::resolve_with_rules("fooBar", &whole), ["foobar"]);
+
+    let split = RuleSet {
+        post_pass_rules: vec![BoundStart(CaseChangeNonAcronym)],
+        ..whole
+    };
+    assert_eq!(WordBoundResolver::<Charwalk>::resolve_with_rules("fooBar", &split), ["foo", "bar"]);
+}
+
+#[test]
+fn words_trimmed_by_a_pass_are_still_slices_of_the_input() {
+    let rules = with_post(vec![Remove(Char('x'), Ends(SingleWord))]);
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("xylo-xenonx", &rules);
+    let spans: Vec<_> = segments
+        .iter()
+        .map(|segment| (segment.as_str(), segment.byte_range.clone(), segment.char_range.clone()))
+        .collect();
+    assert_eq!(spans, [("ylo", 1..4, 1..4), ("enon", 6..10, 6..10)]);
+    assert!(segments.iter().all(|segment| matches!(segment.text, Cow::Borrowed(_))));
+}
+
+#[test]
+fn words_cut_by_the_pre_pass_are_found_again_in_the_input() {
+    let rules = with_pre(vec![Remove(Char('\''), All)]);
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("I don't", &rules);
+    let dont = &segments[1];
+    assert_eq!(dont.as_str(), "dont");
+    assert!(matches!(dont.text, Cow::Owned(_)));
+    assert_eq!(dont.byte_range, 2..7);
+}
+
+struct PrivateNames;
+
+impl ResolverRules for PrivateNames {
+    fn pre_pass_rules() -> Vec<ResolverProcessingRule> {
+        vec![Remove(Char('_'), Prepended(FullInput))]
+    }
+
+    fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+        DefaultRules::resolution_pass_rules()
+    }
+
+    fn post_pass_rules() -> Vec<ResolverProcessingRule> {
+        vec![Remove(PunctSpecialChar, All)]
+    }
+}
+
+#[test]
+fn passes_declared_on_a_rules_type_apply_to_its_static_calls() {
+    assert_eq!(
+        WordBoundResolver::<Charwalk<PrivateNames>, PrivateNames>::resolve("__private_name_"),
+        ["private", "name"]
+    );
+    let segmenter: Segmenter = Segmenter::new(RuleSet::of::<PrivateNames>());
+    assert_eq!(segmenter.resolve("__private_name_"), ["private", "name"]);
+}
@@ -0,0 +1,179 @@
+//! Removal rules by where in a word or in the input they apply, the same for every backend.
+
+use std::borrow::Cow;
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::{Appended, Ends, Middle, Prepended};
+use word_bounds::rules::ResolverProcessingRule::Remove;
+use word_bounds::rules::RuleTarget::{Char, PunctSpecialChar};
+use word_bounds::rules::Scope::{FullInput, SingleWord};
+use word_bounds::rules::{ResolverProcessingRule, RuleSet};
+
+fn with(rule: ResolverProcessingRule) -> RuleSet {
+    let mut rules = RuleSet::default();
+    rules.resolution_pass_rules.push(rule);
+    rules
+}
+
+type Cases = &'static [(&'static str, &'static [&'static str])];
+
+/// Removal within words: each ruleset with inputs and the words every backend resolves them to.
+fn within_words() -> Vec<(RuleSet, Cases)> {
+    vec![
+        (
+            with(Remove(Char('\''), Middle(SingleWord))),
+            &[
+                ("don't stop", &["dont", "stop"]),
+                ("rock'n'roll", &["rocknroll"]),
+                // the start of a word is not its middle
+                ("'tis", &["'tis"]),
+            ],
+        ),
+        (
+            with(Remove(Char('\''), Ends(SingleWord))),
+            &[
+                ("'quoted' words", &["quoted", "words"]),
+                // the middle of a word is not its ends
+                ("don't", &["don't"]),
+            ],
+        ),
+        // punctuation the default rules keep as words of its own is all ends, and goes entirely
+        (with(Remove(PunctSpecialChar, Ends(SingleWord))), &[("_private_", &["private"])]),
+        (
+            with(Remove(PunctSpecialChar, Middle(SingleWord))),
+            &[("_private_", &["_", "private", "_"])],
+        ),
+    ]
+}
+
+#[test]
+fn charwalk_removes_within_words() {
+    for (rules, cases) in within_words() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Charwalk>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_removes_within_words() {
+    for (rules, cases) in within_words() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<FancyRegex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_removes_within_words() {
+    for (rules, cases) in within_words() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Regex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn a_word_with_its_middle_removed_spans_what_it_was_cut_from() {
+    let rules = with(Remove(Char('\''), Middle(SingleWord)));
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("I don't", &rules);
+    let dont = &segments[1];
+    assert_eq!(dont.as_str(), "dont");
+    assert!(matches!(dont.text, Cow::Owned(_)));
+    assert_eq!((dont.byte_range.clone(), dont.char_range.clone()), (2..7, 2..7));
+}
+
+#[test]
+fn a_word_with_its_ends_removed_is_still_a_slice_of_the_input() {
+    let rules = with(Remove(Char('\''), Ends(SingleWord)));
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("say 'é'", &rules);
+    let quoted = &segments[1];
+    assert_eq!(quoted.text, Cow::Borrowed("é"));
+    assert_eq!((quoted.byte_range.clone(), quoted.char_range.clone()), (5..7, 5..6));
+}
+
+/// Removal at the ends of words and of the input.
+fn at_the_ends() -> Vec<(RuleSet, Cases)> {
+    vec![
+        (
+            with(Remove(Char('_'), Prepended(FullInput))),
+            &[
+                ("__private_name_", &["private", "name", "_"]),
+                // trailing ones are kept
+                ("type_", &["type", "_"]),
+            ],
+        ),
+        (
+            with(Remove(Char('_'), Appended(FullInput))),
+            &[
+                ("type_", &["type"]),
+                // leading ones are kept
+                ("_private", &["_", "private"]),
+            ],
+        ),
+        (
+            with(Remove(Char('\''), Prepended(SingleWord))),
+            &[("'tis 'twas", &["tis", "twas"]), ("dogs'", &["dogs'"])],
+        ),
+        (
+            with(Remove(Char('\''), Appended(SingleWord))),
+            &[("dogs' cats'", &["dogs", "cats"]), ("'tis", &["'tis"])],
+        ),
+        // punctuation still separates words when a rule removes it from within them
+        (
+            with(Remove(Char('_'), Appended(SingleWord))),
+            &[("snake_case", &["snake", "case"])],
+        ),
+    ]
+}
+
+#[test]
+fn charwalk_removes_at_the_ends() {
+    for (rules, cases) in at_the_ends() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Charwalk>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_removes_at_the_ends() {
+    for (rules, cases) in at_the_ends() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<FancyRegex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_removes_at_the_ends() {
+    for (rules, cases) in at_the_ends() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Regex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn what_leads_the_input_is_cut_from_its_span() {
+    let rules = with(Remove(Char('_'), Prepended(FullInput)));
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("__private", &rules);
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0].text, Cow::Borrowed("private"));
+    assert_eq!(segments[0].byte_range, 2..9);
+}
@@ -0,0 +1,141 @@
+//! Rulesets as values, chosen while the program runs rather than when it is compiled.
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::ResolverProcessingRule::BoundStart;
+use word_bounds::rules::RuleTarget::Char;
+use word_bounds::rules::{CaseMode, DefaultRules, RuleSet};
+
+const INPUTS: &[&str] = &[
+    "This_is_SomeRandom_Text-to-split2",
+    "+This_is_SomeRandom%Text#to-split2",
+    "...ellipses... could ... be hard...",
+    "thisExampleHasIDELikeACRONYMS",
+    "hashtag#rust",
+];
+
+#[test]
+fn the_default_ruleset_is_the_default_rules() {
+    assert_eq!(RuleSet::default(), RuleSet::of::<DefaultRules>());
+}
+
+#[test]
+fn charwalk_segments_the_default_value_as_the_default_type() {
+    let rules = RuleSet::default();
+    for input in INPUTS {
+        assert_eq!(
+            WordBoundResolver::<Charwalk, DefaultRules>::resolve_with_rules(input, &rules),
+            WordBoundResolver::<Charwalk, DefaultRules>::resolve(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            WordBoundResolver::<Charwalk, DefaultRules>::segments_with_rules(input, &rules),
+            WordBoundResolver::<Charwalk, DefaultRules>::segments(input),
+            "on {input:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_segments_the_default_value_as_the_default_type() {
+    let rules = RuleSet::default();
+    for input in INPUTS {
+        assert_eq!(
+            WordBoundResolver::<FancyRegex, DefaultRules>::resolve_with_rules(input, &rules),
+            WordBoundResolver::<FancyRegex, DefaultRules>::resolve(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            WordBoundResolver::<FancyRegex, DefaultRules>::segments_with_rules(input, &rules),
+            WordBoundResolver::<FancyRegex, DefaultRules>::segments(input),
+            "on {input:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_segments_the_default_value_as_the_default_type() {
+    let rules = RuleSet::default();
+    for input in INPUTS {
+        assert_eq!(
+            WordBoundResolver::<Regex, DefaultRules>::resolve_with_rules(input, &rules),
+            WordBoundResolver::<Regex, DefaultRules>::resolve(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            WordBoundResolver::<Regex, DefaultRules>::segments_with_rules(input, &rules),
+            WordBoundResolver::<Regex, DefaultRules>::segments(input),
+            "on {input:?}"
+        );
+    }
+}
+
+#[test]
+fn a_rule_taken_out_at_run_time_no_longer_applies() {
+    let mut rules = RuleSet::default();
+    rules.resolution_pass_rules.retain(|rule| rule != &BoundStart(Char('#')));
+    assert_eq!(
+        WordBoundResolver::<Charwalk, DefaultRules>::resolve_with_rules("hashtag#rust", &rules),
+        ["hashtag", "#", "rust"]
+    );
+    assert_eq!(
+        WordBoundResolver::<Charwalk, DefaultRules>::resolve("hashtag#rust"),
+        ["hashtag", "#rust"]
+    );
+}
+
+#[test]
+fn punctuation_set_at_run_time_separates_words() {
+    let mut rules = RuleSet::default();
+    rules.set_punct_chars("-_.,:;?! /");
+    assert_eq!(rules.punct_chars_non_regex, "-_.,:;?! /");
+    assert_eq!(
+        WordBoundResolver::<Charwalk, DefaultRules>::resolve_with_rules("json/Response", &rules),
+        ["json", "response"]
+    );
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn punctuation_set_at_run_time_reaches_the_regex_pattern() {
+    let mut rules = RuleSet::default();
+    rules.set_punct_chars("-_.,:;?! /");
+    assert_eq!(
+        WordBoundResolver::<FancyRegex, DefaultRules>::resolve_with_rules("json/Response", &rules),
+        ["json", "response"]
+    );
+}
+
+#[test]
+fn the_case_mode_is_part_of_the_value() {
+    let rules = RuleSet {
+        case_mode: CaseMode::Preserve,
+        ..RuleSet::default()
+    };
+    assert_eq!(
+        WordBoundResolver::<Charwalk, DefaultRules>::resolve_with_rules("JSONResponse", &rules),
+        ["JSON", "Response"]
+    );
+}
+
+#[test]
+fn rulesets_chosen_per_call_do_not_leak_into_each_other() {
+    // One service, one ruleset per tenant, the same backend for both.
+    let mut hashtags_apart = RuleSet::default();
+    hashtags_apart.resolution_pass_rules.retain(|rule| rule != &BoundStart(Char('#')));
+    let tenants = [RuleSet::default(), hashtags_apart];
+
+    let outcomes: Vec<Vec<String>> = tenants
+        .iter()
+        .map(|rules| {
+            WordBoundResolver::<Charwalk, DefaultRules>::resolve_with_rules("a#b", rules)
+        })
+        .collect();
+    assert_eq!(outcomes, [vec!["a", "#b"], vec!["a", "#", "b"]]);
+}
@@ -28,13 +28,45 @@ mod tests {
         ("WordWithNumbers123", &["word", "with", "numbers", "123"]),
         ("Short1", &["short", "1"]),
         ("number123456", &["number", "123456"]),
+        ("2024", &["2024"]),
+        ("12ab", &["12", "ab"]),
+        ("a12b", &["a", "12", "b"]),
         ("someHTML", &["some", "html"]),
         ("JSONResponse", &["json", "response"]),
+        // a trailing s on an acronym is its plural, unless lower case follows
+        ("URLs", &["urls"]),
+        ("parseURLsFast", &["parse", "urls", "fast"]),
+        ("IDs_and_URLs", &["ids", "and", "urls"]),
+        ("HTMLParser", &["html", "parser"]),
+        ("ASsociate", &["a", "ssociate"]),
         (
             "WithSpecial-_*Characters",
             &["with", "special", "*", "characters"],
         ),
         ("hashtag#rust", &["hashtag", "#rust"]),
+        // a titlecase letter starts a word, as a capital does
+        ("fooǅungla", &["foo", "ǆungla"]),
+        ("HTMLǅungla", &["html", "ǆungla"]),
+        ("ǅunglaBar", &["ǆungla", "bar"]),
+        ("xᾨδή", &["x", "ᾠδή"]),
+        // and a sigma ending a word lowercases to the final form
+        ("ΟΔΟΣΚαι", &["οδος", "και"]),
+        ("ΟδόςΚαι", &["οδός", "και"]),
+        // outside ASCII, punctuation separates and between words is dropped
+        ("foo—bar", &["foo", "bar"]),
+        ("a…b", &["a", "b"]),
+        ("a、b。", &["a", "b", "。"]),
+        ("a·b", &["a", "b"]),
+        ("¿Qué?", &["¿", "qué", "?"]),
+        // brackets, quotes and symbols are special characters, words of their own
+        ("«Bonjour», dit-il", &["«", "bonjour", "»", "dit", "il"]),
+        ("5€ off", &["5", "€", "off"]),
+        ("emoji🎉party", &["emoji", "🎉", "party"]),
+        // and a letter is a letter in any script
+        ("ThisIsÜberCool", &["this", "is", "über", "cool"]),
+        ("日本語テキスト", &["日本語テキスト"]),
+        // a selector stays with the symbol it shows as an emoji
+        ("maybe ⚠\u{FE0F} ok", &["maybe", "⚠\u{FE0F}", "ok"]),
         // MORE COMPLICATED MIXTURES
         (
             "+This_is_SomeRandom%Text#to-split2",
@@ -82,15 +114,7 @@ mod tests {
         ),
     ];
 
-    // Catalogued, not passing. The regex and fancy_regex backends do not implement
-    // `PunctSpecialCharRun`, so they split `...` into separate tokens where charwalk reads it as
-    // one. Both backends say so at their `RuleTarget::PunctSpecialCharRun` arm. The assertion
-    // below states the INTENDED behaviour and is left as the specification of the gap rather than
-    // weakened to match what the backends currently do.
-    //
-    // Run them with `cargo test --features use_regex,use_fancy_regex -- --ignored`.
     #[test]
-    #[ignore = "catalogue: fancy_regex does not segment punctuation runs; charwalk does"]
     #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
     fn test_word_bounds_fancy_regex() {
         for (input, expected) in TEST_DEFAULT {
@@ -101,15 +125,7 @@ mod tests {
         }
     }
 
-    // Catalogued, not passing. The regex and fancy_regex backends do not implement
-    // `PunctSpecialCharRun`, so they split `...` into separate tokens where charwalk reads it as
-    // one. Both backends say so at their `RuleTarget::PunctSpecialCharRun` arm. The assertion
-    // below states the INTENDED behaviour and is left as the specification of the gap rather than
-    // weakened to match what the backends currently do.
-    //
-    // Run them with `cargo test --features use_regex,use_fancy_regex -- --ignored`.
     #[test]
-    #[ignore = "catalogue: regex does not segment punctuation runs; charwalk does"]
     #[cfg(any(feature = "use_regex", feature = "benchmark"))]
     fn test_word_bounds_regex() {
         for (input, expected) in TEST_DEFAULT {
@@ -150,3 +166,438 @@ mod edge_cases {
         assert_eq!(WordBoundResolver::<Charwalk, DefaultRules>::resolve("a"), ["a"]);
     }
 }
+
+#[cfg(test)]
+mod borrowed {
+    use std::borrow::Cow;
+
+    use word_bounds::impls::charwalk::Charwalk;
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    use word_bounds::impls::fancy_regex::FancyRegex;
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    use word_bounds::impls::regex::Regex;
+    use word_bounds::resolver::WordBoundResolver;
+    use word_bounds::rules::RemoveMode::All;
+    use word_bounds::rules::ResolverProcessingRule::Remove;
+    use word_bounds::rules::RuleTarget::Char;
+    use word_bounds::rules::{DefaultRules, ResolverProcessingRule, ResolverRules};
+
+    const INPUTS: &[&str] = &[
+        "This_is_SomeRandom_Text-to-split2",
+        "JSONResponse",
+        "thisExampleHasIDELikeACRONYMS",
+        "hashtag#rust",
+        "WordWithNumbers123",
+    ];
+
+    /// Every word is a slice of the input, and lowercasing them gives what `resolve` gives.
+    fn assert_borrowed(input: &str, borrowed: &[Cow<'_, str>], resolved: Vec<String>) {
+        let input_range = input.as_bytes().as_ptr_range();
+        for word in borrowed {
+            match word {
+                Cow::Borrowed(slice) => assert!(
+                    input_range.contains(&slice.as_ptr()),
+                    "{slice:?} does not point into {input:?}"
+                ),
+                Cow::Owned(owned) => panic!("{owned:?} of {input:?} was copied out"),
+            }
+        }
+        let lowered: Vec<String> = borrowed.iter().map(|w| w.to_lowercase()).collect();
+        assert_eq!(lowered, resolved);
+    }
+
+    #[test]
+    fn charwalk_borrows_from_the_input() {
+        for input in INPUTS {
+            let borrowed = WordBoundResolver::<Charwalk, DefaultRules>::resolve_borrowed(input);
+            let resolved = WordBoundResolver::<Charwalk, DefaultRules>::resolve(input);
+            assert_borrowed(input, &borrowed, resolved);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    fn fancy_regex_borrows_from_the_input() {
+        for input in INPUTS {
+            let borrowed = WordBoundResolver::<FancyRegex, DefaultRules>::resolve_borrowed(input);
+            let resolved = WordBoundResolver::<FancyRegex, DefaultRules>::resolve(input);
+            assert_borrowed(input, &borrowed, resolved);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    fn regex_borrows_from_the_input() {
+        for input in INPUTS {
+            let borrowed = WordBoundResolver::<Regex, DefaultRules>::resolve_borrowed(input);
+            let resolved = WordBoundResolver::<Regex, DefaultRules>::resolve(input);
+            assert_borrowed(input, &borrowed, resolved);
+        }
+    }
+
+    struct RemoveApostrophes;
+
+    impl ResolverRules for RemoveApostrophes {
+        fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+            vec![Remove(Char('\''), All)]
+        }
+    }
+
+    #[test]
+    fn a_word_with_something_removed_from_inside_is_copied_out() {
+        type Resolver<'a> = WordBoundResolver<'a, Charwalk<RemoveApostrophes>, RemoveApostrophes>;
+        let words = Resolver::resolve_borrowed("don't");
+        assert_eq!(words, ["dont"]);
+        assert!(matches!(words[0], Cow::Owned(_)));
+    }
+
+    #[test]
+    fn case_is_left_as_it_was() {
+        assert_eq!(
+            WordBoundResolver::<Charwalk, DefaultRules>::resolve_borrowed("JSONResponse"),
+            ["JSON", "Response"]
+        );
+    }
+}
+
+#[cfg(test)]
+mod spans {
+    use word_bounds::impls::charwalk::Charwalk;
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    use word_bounds::impls::fancy_regex::FancyRegex;
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    use word_bounds::impls::regex::Regex;
+    use word_bounds::resolver::WordBoundResolver;
+    use word_bounds::rules::RemoveMode::All;
+    use word_bounds::rules::ResolverProcessingRule::Remove;
+    use word_bounds::rules::RuleTarget::Char;
+    use word_bounds::rules::{DefaultRules, ResolverProcessingRule, ResolverRules};
+    use word_bounds::segment::Segment;
+
+    const INPUTS: &[&str] = &[
+        "This_is_SomeRandom_Text-to-split2",
+        "...ellipses... could ... be hard...",
+        "hashtag#rust",
+        "Grüße_Welt",
+    ];
+
+    /// Each range slices the input back to the word, and counts the same in chars as in bytes.
+    fn assert_points_back(input: &str, segments: &[Segment<'_>]) {
+        for segment in segments {
+            assert_eq!(&input[segment.byte_range.clone()], segment.as_str(), "in {input:?}");
+            let by_chars: String = input
+                .chars()
+                .skip(segment.char_range.start)
+                .take(segment.char_range.len())
+                .collect();
+            assert_eq!(by_chars, segment.as_str(), "in {input:?}");
+        }
+    }
+
+    #[test]
+    fn charwalk_ranges_point_back_at_the_input() {
+        for input in INPUTS {
+            let segments = WordBoundResolver::<Charwalk, DefaultRules>::segments(input);
+            assert_points_back(input, &segments);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    fn fancy_regex_ranges_point_back_at_the_input() {
+        for input in INPUTS {
+            let segments = WordBoundResolver::<FancyRegex, DefaultRules>::segments(input);
+            assert_points_back(input, &segments);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    fn regex_ranges_point_back_at_the_input() {
+        for input in INPUTS {
+            let segments = WordBoundResolver::<Regex, DefaultRules>::segments(input);
+            assert_points_back(input, &segments);
+        }
+    }
+
+    #[test]
+    fn char_ranges_count_characters_not_bytes() {
+        let segments = WordBoundResolver::<Charwalk, DefaultRules>::segments("Grüße_Welt");
+        let ranges: Vec<_> =
+            segments.iter().map(|s| (s.byte_range.clone(), s.char_range.clone())).collect();
+        assert_eq!(ranges, [(0..7, 0..5), (8..12, 6..10)]);
+    }
+
+    struct RemoveApostrophes;
+
+    impl ResolverRules for RemoveApostrophes {
+        fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+            vec![Remove(Char('\''), All)]
+        }
+    }
+
+    #[test]
+    fn a_removal_inside_a_word_keeps_its_whole_extent() {
+        type Resolver<'a> = WordBoundResolver<'a, Charwalk<RemoveApostrophes>, RemoveApostrophes>;
+        let segments = Resolver::segments("don't");
+        assert_eq!(segments[0].as_str(), "dont");
+        assert_eq!(segments[0].byte_range, 0..5);
+        assert_eq!(segments[0].char_range, 0..5);
+    }
+
+    #[test]
+    fn a_removed_separator_lies_between_the_ranges_it_separated() {
+        let segments = WordBoundResolver::<Charwalk, DefaultRules>::segments("snake_case");
+        assert_eq!(segments[0].byte_range, 0..5);
+        assert_eq!(segments[1].byte_range, 6..10);
+    }
+}
+
+#[cfg(test)]
+mod lazy {
+    use word_bounds::impls::charwalk::Charwalk;
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    use word_bounds::impls::fancy_regex::FancyRegex;
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    use word_bounds::impls::regex::Regex;
+    use word_bounds::resolver::WordBoundResolver;
+    use word_bounds::rules::DefaultRules;
+
+    const INPUTS: &[&str] = &[
+        "This_is_SomeRandom_Text-to-split2",
+        "...ellipses... could ... be hard...",
+        "+This_is_SomeRandom%Text#to-split2",
+        "a",
+        "",
+    ];
+
+    #[test]
+    fn charwalk_iterating_gives_what_collecting_gives() {
+        for input in INPUTS {
+            let iterated: Vec<_> =
+                WordBoundResolver::<Charwalk, DefaultRules>::iter(input).collect();
+            assert_eq!(iterated, WordBoundResolver::<Charwalk, DefaultRules>::segments(input));
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    fn fancy_regex_iterating_gives_what_collecting_gives() {
+        for input in INPUTS {
+            let iterated: Vec<_> =
+                WordBoundResolver::<FancyRegex, DefaultRules>::iter(input).collect();
+            assert_eq!(iterated, WordBoundResolver::<FancyRegex, DefaultRules>::segments(input));
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    fn regex_iterating_gives_what_collecting_gives() {
+        for input in INPUTS {
+            let iterated: Vec<_> =
+                WordBoundResolver::<Regex, DefaultRules>::iter(input).collect();
+            assert_eq!(iterated, WordBoundResolver::<Regex, DefaultRules>::segments(input));
+        }
+    }
+
+    #[test]
+    fn stopping_early_on_a_long_input_gives_the_first_words() {
+        let input = "firstWord second_word ".repeat(1 << 18);
+        let first: Vec<String> = WordBoundResolver::<Charwalk, DefaultRules>::iter(&input)
+            .take(3)
+            .map(|segment| segment.text.into_owned())
+            .collect();
+        assert_eq!(first, ["first", "Word", "second"]);
+
+        let found = WordBoundResolver::<Charwalk, DefaultRules>::iter(&input)
+            .find(|segment| segment.as_str() == "word")
+            .expect("found");
+        assert_eq!(found.byte_range, 17..21);
+    }
+}
+
+#[cfg(test)]
+mod kinds {
+    use word_bounds::impls::charwalk::Charwalk;
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    use word_bounds::impls::fancy_regex::FancyRegex;
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    use word_bounds::impls::regex::Regex;
+    use word_bounds::resolver::WordBoundResolver;
+    use word_bounds::rules::ResolverProcessingRule::BoundStart;
+    use word_bounds::rules::RuleTarget::CaseChangeNonAcronym;
+    use word_bounds::rules::{DefaultRules, ResolverProcessingRule, ResolverRules};
+    use word_bounds::segment::SegmentKind::{self, *};
+
+    /// Each input with its words and what each is tagged as, by every backend.
+    const TAGGED: &[(&str, &[(&str, SegmentKind)])] = &[
+        (
+            "+This_is_SomeRandom%Text#to-split2",
+            &[
+                ("+", Special),
+                ("This", Word),
+                ("is", Word),
+                ("Some", Word),
+                ("Random%", Suffixed('%')),
+                ("Text", Word),
+                ("#to", Prefixed('#')),
+                ("split", Word),
+                ("2", Numeric),
+            ],
+        ),
+        (
+            "JSONResponse... ⚠️",
+            &[
+                ("JSON", Acronym),
+                ("Response", Word),
+                ("...", PunctRun),
+                ("⚠\u{fe0f}", Special),
+            ],
+        ),
+        ("JSONResponse", &[("JSON", Acronym), ("Response", Word)]),
+        (
+            "WordWithNumbers123",
+            &[("Word", Word), ("With", Word), ("Numbers", Word), ("123", Numeric)],
+        ),
+        ("hashtag#rust", &[("hashtag", Word), ("#rust", Prefixed('#'))]),
+        ("snake_case", &[("snake", Word), ("case", Word)]),
+        // punctuation and symbols outside ASCII are tagged as those inside it are
+        (
+            "¿Qué «x»?",
+            &[
+                ("¿", Punct),
+                ("Qué", Word),
+                ("«", Special),
+                ("x", Word),
+                ("»", Special),
+                ("?", Punct),
+            ],
+        ),
+        // a lone separator kept at the end is punctuation
+        ("AppendedUnderscore_", &[("Appended", Word), ("Underscore", Word), ("_", Punct)]),
+    ];
+
+    #[test]
+    fn charwalk_tags_each_target() {
+        for (input, expected) in TAGGED {
+            let segments = WordBoundResolver::<Charwalk, DefaultRules>::segments(input);
+            let kinds: Vec<_> = segments.iter().map(|s| (s.as_str(), s.kind)).collect();
+            assert_eq!(kinds, *expected, "on {input:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_fancy_regex", feature = "benchmark"))]
+    fn fancy_regex_tags_each_target() {
+        for (input, expected) in TAGGED {
+            let segments = WordBoundResolver::<FancyRegex, DefaultRules>::segments(input);
+            let kinds: Vec<_> = segments.iter().map(|s| (s.as_str(), s.kind)).collect();
+            assert_eq!(kinds, *expected, "on {input:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "use_regex", feature = "benchmark"))]
+    fn regex_tags_each_target() {
+        for (input, expected) in TAGGED {
+            let segments = WordBoundResolver::<Regex, DefaultRules>::segments(input);
+            let kinds: Vec<_> = segments.iter().map(|s| (s.as_str(), s.kind)).collect();
+            assert_eq!(kinds, *expected, "on {input:?}");
+        }
+    }
+
+    struct CaseOnly;
+
+    impl ResolverRules for CaseOnly {
+        fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+            vec![BoundStart(CaseChangeNonAcronym)]
+        }
+    }
+
+    #[test]
+    fn a_target_the_rules_do_not_name_classifies_nothing() {
+        for input in ["HTTP", "200"] {
+            let kinds: Vec<_> = WordBoundResolver::<Charwalk<CaseOnly>, CaseOnly>::iter(input)
+                .map(|segment| segment.kind)
+                .collect();
+            assert_eq!(kinds, [Word], "on {input:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod case_modes {
+    use word_bounds::impls::charwalk::Charwalk;
+    use word_bounds::resolver::WordBoundResolver;
+    use word_bounds::rules::{CaseMode, DefaultRules, ResolverProcessingRule, ResolverRules};
+
+    macro_rules! rules_with_case {
+        ($name:ident, $mode:expr) => {
+            struct $name;
+
+            impl ResolverRules for $name {
+                fn resolution_pass_rules() -> Vec<ResolverProcessingRule> {
+                    DefaultRules::resolution_pass_rules()
+                }
+
+                fn case_mode() -> CaseMode {
+                    $mode
+                }
+            }
+        };
+    }
+
+    rules_with_case!(Preserving, CaseMode::Preserve);
+    rules_with_case!(Upper, CaseMode::Upper);
+    rules_with_case!(Folding, CaseMode::Fold);
+
+    #[test]
+    fn lower_is_the_default() {
+        assert_eq!(DefaultRules::case_mode(), CaseMode::Lower);
+        assert_eq!(
+            WordBoundResolver::<Charwalk, DefaultRules>::resolve("JSONResponse"),
+            ["json", "response"]
+        );
+    }
+
+    #[test]
+    fn preserve_keeps_the_input_case() {
+        assert_eq!(
+            WordBoundResolver::<Charwalk<Preserving>, Preserving>::resolve("JSONResponse"),
+            ["JSON", "Response"]
+        );
+    }
+
+    #[test]
+    fn upper_upper_cases() {
+        assert_eq!(
+            WordBoundResolver::<Charwalk<Upper>, Upper>::resolve("JSONResponse"),
+            ["JSON", "RESPONSE"]
+        );
+    }
+
+    #[test]
+    fn fold_reaches_the_caseless_form() {
+        assert_eq!(
+            WordBoundResolver::<Charwalk<Folding>, Folding>::resolve("Straße_ΟΔΟΣ"),
+            ["strasse", "οδοσ"]
+        );
+        assert_eq!(CaseMode::Lower.apply("ΟΔΟΣ"), "οδος");
+    }
+
+    #[test]
+    fn a_titlecase_letter_keeps_its_case_until_asked() {
+        assert_eq!(
+            WordBoundResolver::<Charwalk<Preserving>, Preserving>::resolve("ΟΔΟΣǅungla"),
+            ["ΟΔΟΣ", "ǅungla"]
+        );
+        assert_eq!(
+            WordBoundResolver::<Charwalk<Upper>, Upper>::resolve("ΟΔΟΣǅungla"),
+            ["ΟΔΟΣ", "ǄUNGLA"]
+        );
+        assert_eq!(
+            WordBoundResolver::<Charwalk<Folding>, Folding>::resolve("ΟΔΟΣǅungla"),
+            ["οδοσ", "ǆungla"]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! A ruleset prepared once and applied to many inputs, from one thread or several.
+
+use std::sync::Arc;
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::ResolverProcessingRule::BoundStart;
+use word_bounds::rules::RuleTarget::Char;
+use word_bounds::rules::{CaseMode, DefaultRules, RuleSet};
+use word_bounds::segmenter::Segmenter;
+
+const INPUTS: &[&str] = &[
+    "This_is_SomeRandom_Text-to-split2",
+    "+This_is_SomeRandom%Text#to-split2",
+    "...ellipses... could ... be hard...",
+    "thisExampleHasIDELikeACRONYMS",
+    "hashtag#rust",
+    "",
+];
+
+#[test]
+fn charwalk_prepared_rules_segment_as_the_static_ones() {
+    let segmenter = Segmenter::<Charwalk>::default();
+    for input in INPUTS {
+        assert_eq!(
+            segmenter.resolve(input),
+            WordBoundResolver::<Charwalk, DefaultRules>::resolve(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            segmenter.resolve_borrowed(input),
+            WordBoundResolver::<Charwalk, DefaultRules>::resolve_borrowed(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            segmenter.segments(input),
+            WordBoundResolver::<Charwalk, DefaultRules>::segments(input),
+            "on {input:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_prepared_rules_segment_as_the_static_ones() {
+    let segmenter = Segmenter::<FancyRegex>::default();
+    for input in INPUTS {
+        assert_eq!(
+            segmenter.resolve(input),
+            WordBoundResolver::<FancyRegex, DefaultRules>::resolve(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            segmenter.resolve_borrowed(input),
+            WordBoundResolver::<FancyRegex, DefaultRules>::resolve_borrowed(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            segmenter.segments(input),
+            WordBoundResolver::<FancyRegex, DefaultRules>::segments(input),
+            "on {input:?}"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_prepared_rules_segment_as_the_static_ones() {
+    let segmenter = Segmenter::<Regex>::default();
+    for input in INPUTS {
+        assert_eq!(
+            segmenter.resolve(input),
+            WordBoundResolver::<Regex, DefaultRules>::resolve(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            segmenter.resolve_borrowed(input),
+            WordBoundResolver::<Regex, DefaultRules>::resolve_borrowed(input),
+            "on {input:?}"
+        );
+        assert_eq!(
+            segmenter.segments(input),
+            WordBoundResolver::<Regex, DefaultRules>::segments(input),
+            "on {input:?}"
+        );
+    }
+}
+
+#[test]
+fn a_segmenter_keeps_the_rules_it_was_made_with() {
+    let mut rules = RuleSet::default();
+    rules.resolution_pass_rules.retain(|rule| rule != &BoundStart(Char('#')));
+    rules.case_mode = CaseMode::Preserve;
+    let segmenter = Segmenter::<Charwalk>::new(rules.clone());
+
+    assert_eq!(segmenter.rules(), &rules);
+    for _ in 0..2 {
+        assert_eq!(segmenter.resolve("hashtag#Rust"), ["hashtag", "#", "Rust"]);
+    }
+}
+
+#[test]
+fn one_segmenter_serves_many_threads() {
+    let segmenter = Arc::new(Segmenter::<Charwalk>::default());
+    let expected: Vec<Vec<String>> = INPUTS.iter().map(|input| segmenter.resolve(input)).collect();
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            let segmenter = Arc::clone(&segmenter);
+            let expected = &expected;
+            scope.spawn(move || {
+                for _ in 0..100 {
+                    for (input, words) in INPUTS.iter().zip(expected) {
+                        assert_eq!(&segmenter.resolve(input), words);
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn a_prepared_pattern_serves_many_threads() {
+    let mut rules = RuleSet::default();
+    rules.set_punct_chars("-_.,:;?! /");
+    let segmenter = Segmenter::<FancyRegex>::new(rules);
+
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    assert_eq!(segmenter.resolve("json/Response"), ["json", "response"]);
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,179 @@
+//! Rules naming a string of characters rather than one.
+
+use std::borrow::Cow;
+use std::ops::Range;
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::{All, Appended, Ends, Prepended};
+use word_bounds::rules::ResolverProcessingRule::{BoundEnd, BoundStart, Remove};
+use word_bounds::rules::RuleTarget::{Char, String};
+use word_bounds::rules::Scope::{FullInput, SingleWord};
+use word_bounds::rules::{ResolverProcessingRule, RuleSet};
+use word_bounds::segment::Segment;
+
+fn with(rules: Vec<ResolverProcessingRule>) -> RuleSet {
+    let mut ruleset = RuleSet::default();
+    ruleset.resolution_pass_rules.extend(rules);
+    ruleset
+}
+
+type Cases = &'static [(&'static str, &'static [&'static str])];
+type Spans = Vec<(&'static str, Range<usize>, bool)>;
+
+/// Each ruleset with inputs and the words every backend resolves them to.
+fn resolved() -> Vec<(RuleSet, Cases)> {
+    let string = |s: &str| String(s.into());
+    let mut arrows_pre_pass = RuleSet::default();
+    arrows_pre_pass.pre_pass_rules.push(Remove(string("->"), All));
+    let mut suffixes = RuleSet::default();
+    suffixes.post_pass_rules.push(Remove(string("ing"), Appended(SingleWord)));
+    let mut prefixes = RuleSet::default();
+    prefixes.post_pass_rules.push(Remove(string("re"), Prepended(FullInput)));
+    vec![
+        // a string is bounded as a whole, and its characters answer to its rules alone
+        (
+            with(vec![BoundStart(string("::"))]),
+            &[
+                ("std::io::Read", &["std", "::io", "::read"]),
+                ("foo::bar", &["foo", "::bar"]),
+                ("::std", &["::std"]),
+                ("std::", &["std", "::"]),
+                ("std:: io", &["std", "::", "io"]),
+                ("a.::b", &["a", "::b"]),
+            ],
+        ),
+        (
+            with(vec![BoundEnd(string("..."))]),
+            &[
+                ("wait... what", &["wait...", "what"]),
+                ("wait ... what", &["wait", "...", "what"]),
+                ("a...b", &["a...", "b"]),
+                ("...x", &["...", "x"]),
+            ],
+        ),
+        (
+            with(vec![BoundEnd(string("::"))]),
+            &[("std::io::Read", &["std::", "io::", "read"])],
+        ),
+        (
+            with(vec![BoundStart(string("->")), BoundEnd(string("->"))]),
+            &[
+                ("a->b", &["a", "->", "b"]),
+                ("foo->bar->baz", &["foo", "->", "bar", "->", "baz"]),
+                ("->a", &["->", "a"]),
+                ("a->", &["a", "->"]),
+            ],
+        ),
+        (
+            with(vec![BoundStart(string("->"))]),
+            &[("a->b", &["a", "->b"]), ("a->2", &["a", "->", "2"])],
+        ),
+        (with(vec![BoundStart(string("Bar"))]), &[("fooBarbaz", &["foo", "barbaz"])]),
+        (with(vec![BoundEnd(string("Bar"))]), &[("fooBarbaz", &["foobar", "baz"])]),
+        // a removed string only bounds where a rule says so
+        (
+            with(vec![Remove(string("->"), All)]),
+            &[
+                ("a->b", &["ab"]),
+                // its characters alone are not the string
+                ("a-b", &["a", "b"]),
+            ],
+        ),
+        (with(vec![Remove(string("Bar"), All)]), &[("fooBarBaz", &["foo", "baz"])]),
+        (
+            with(vec![Remove(string("->"), All), BoundStart(string("->"))]),
+            &[("a->b", &["a", "b"])],
+        ),
+        (
+            with(vec![Remove(string("->"), Ends(FullInput))]),
+            &[("->start", &["start"]), ("a->b", &["a->b"])],
+        ),
+        // the longest string starting at a character wins
+        (
+            with(vec![
+                BoundStart(string("::")),
+                BoundStart(string(":::")),
+                BoundEnd(string(":::")),
+            ]),
+            &[("a:::b::c", &["a", ":::", "b", "::c"])],
+        ),
+        // and the passes either side of resolution read strings too
+        (arrows_pre_pass, &[("a->b c", &["ab", "c"])]),
+        (suffixes, &[("running jumping ingot", &["runn", "jump", "ingot"])]),
+        (prefixes, &[("rerun report", &["run", "report"])]),
+    ]
+}
+
+/// Each ruleset with an input and the segments every backend resolves it to, with where each is
+/// in the input and whether it is a copy rather than borrowed from it.
+fn placed() -> Vec<(RuleSet, &'static str, Spans)> {
+    // strings are found in what the pre-pass kept
+    let mut kept = with(vec![BoundStart(String("::".into()))]);
+    kept.pre_pass_rules.push(Remove(Char(' '), All));
+    vec![
+        (kept, "std: :io", vec![("std", 0..3, false), ("::io", 3..8, true)]),
+        // a multibyte string spans its bytes
+        (
+            with(vec![BoundStart(String("→".into())), BoundEnd(String("→".into()))]),
+            "a→b",
+            vec![("a", 0..1, false), ("→", 1..4, false), ("b", 4..5, false)],
+        ),
+    ]
+}
+
+fn spans<'a>(segments: &'a [Segment<'_>]) -> Vec<(&'a str, Range<usize>, bool)> {
+    let span = |segment: &'a Segment<'_>| {
+        let copied = matches!(segment.text, Cow::Owned(_));
+        (segment.as_str(), segment.byte_range.clone(), copied)
+    };
+    segments.iter().map(span).collect()
+}
+
+#[test]
+fn charwalk_reads_strings() {
+    for (rules, cases) in resolved() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Charwalk>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+    for (rules, input, expected) in placed() {
+        let segments = WordBoundResolver::<Charwalk>::segments_with_rules(input, &rules);
+        assert_eq!(spans(&segments), expected, "{input:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_reads_strings() {
+    for (rules, cases) in resolved() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<FancyRegex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+    for (rules, input, expected) in placed() {
+        let segments = WordBoundResolver::<FancyRegex>::segments_with_rules(input, &rules);
+        assert_eq!(spans(&segments), expected, "{input:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_reads_strings() {
+    for (rules, cases) in resolved() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Regex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+    for (rules, input, expected) in placed() {
+        let segments = WordBoundResolver::<Regex>::segments_with_rules(input, &rules);
+        assert_eq!(spans(&segments), expected, "{input:?}");
+    }
+}
@@ -0,0 +1,94 @@
+//! The `unicode` backend: charwalk, with UAX #29 deciding where the rules have nothing to say.
+
+#![cfg(feature = "enhanced_accuracy")]
+
+use word_bounds::capabilities::validate;
+use word_bounds::impls::charwalk::Charwalk;
+use word_bounds::impls::unicode::UnicodeWords;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::RemoveMode::{All, Prepended};
+use word_bounds::rules::ResolverProcessingRule::{BoundStart, Remove};
+use word_bounds::rules::RuleTarget::{Char, String};
+use word_bounds::rules::Scope::FullInput;
+use word_bounds::rules::{ResolverProcessingRule, RuleSet};
+use word_bounds::segment::SegmentKind;
+
+fn with(rules: &[ResolverProcessingRule]) -> RuleSet {
+    let mut set = RuleSet::default();
+    set.resolution_pass_rules.extend_from_slice(rules);
+    set
+}
+
+#[test]
+fn bounds_what_the_rules_have_no_reading_of() {
+    let resolve = WordBoundResolver::<UnicodeWords>::resolve;
+    assert_eq!(resolve("東京タワー"), ["東", "京", "タワー"]);
+    assert_eq!(resolve("emoji🎉party"), ["emoji", "🎉", "party"]);
+    // a sequence joined with zero width joiners is one emoji, and a selector stays with its own
+    assert_eq!(resolve("👨\u{200D}👩\u{200D}👧family"), ["👨\u{200D}👩\u{200D}👧", "family"]);
+    assert_eq!(resolve("⚠\u{FE0F}warning"), ["⚠\u{FE0F}", "warning"]);
+    // and letters outside ASCII are letters, bounded by the rules as any others
+    assert_eq!(resolve("ThisIsÜberCool"), ["this", "is", "über", "cool"]);
+
+    let segments = WordBoundResolver::<UnicodeWords>::segments("a🎉b");
+    assert_eq!(segments[1].kind, SegmentKind::Special);
+    assert_eq!(segments[1].byte_range, 1..5);
+    assert_eq!(segments[2].char_range, 2..3);
+}
+
+#[test]
+fn resolves_as_charwalk_does_where_the_rules_decide() {
+    let rulesets = [
+        RuleSet::default(),
+        with(&[Remove(Char('-'), All)]),
+        with(&[Remove(String("::".into()), All), BoundStart(String("->".into()))]),
+        RuleSet {
+            pre_pass_rules: vec![Remove(Char('_'), Prepended(FullInput))],
+            ..RuleSet::default()
+        },
+    ];
+    let inputs = [
+        "This_is_SomeRandom_Text-to-split2",
+        "parseURLsFast...then 50% #tags",
+        "std::mem->swap a-b",
+        "__private_Field",
+    ];
+    for rules in &rulesets {
+        for input in inputs {
+            assert_eq!(
+                WordBoundResolver::<UnicodeWords>::resolve_with_rules(input, rules),
+                WordBoundResolver::<Charwalk>::resolve_with_rules(input, rules),
+                "{input:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn leaves_a_character_a_rule_names_to_the_rule() {
+    // `§` gets a word of its own from UAX #29, but the rule says it starts one
+    let rules = with(&[BoundStart(Char('§'))]);
+    let words = WordBoundResolver::<UnicodeWords>::resolve_with_rules("see§ref", &rules);
+    assert_eq!(words, ["see", "§ref"]);
+    // and a string to its rules, inside and at either end
+    let rules = with(&[BoundStart(String("→→".into()))]);
+    let words = WordBoundResolver::<UnicodeWords>::resolve_with_rules("a→→b", &rules);
+    assert_eq!(words, ["a", "→→b"]);
+}
+
+#[test]
+fn reads_the_characters_the_pre_pass_closed_up_as_neighbours() {
+    let rules = RuleSet {
+        pre_pass_rules: vec![Remove(Char(' '), All)],
+        ..RuleSet::default()
+    };
+    let words = WordBoundResolver::<UnicodeWords>::resolve_with_rules("caf é🎉", &rules);
+    assert_eq!(words, ["café", "🎉"]);
+}
+
+#[test]
+fn reads_every_rule_charwalk_does() {
+    assert_eq!(validate::<UnicodeWords>(&RuleSet::default()), []);
+    let rules = with(&[BoundStart(Char('§'))]);
+    assert_eq!(validate::<UnicodeWords>(&rules), validate::<Charwalk>(&rules));
+}
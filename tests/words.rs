@@ -0,0 +1,108 @@
+//! Rules over whole words, read once the input is resolved, the same for every backend.
+
+use std::borrow::Cow;
+
+use word_bounds::impls::charwalk::Charwalk;
+#[cfg(feature = "use_fancy_regex")]
+use word_bounds::impls::fancy_regex::FancyRegex;
+#[cfg(feature = "use_regex")]
+use word_bounds::impls::regex::Regex;
+use word_bounds::resolver::WordBoundResolver;
+use word_bounds::rules::Direction::Next;
+use word_bounds::rules::RemoveMode::{All, Prepended};
+use word_bounds::rules::ResolverProcessingRule::{Attach, Remove};
+use word_bounds::rules::RuleTarget::Word;
+use word_bounds::rules::Scope::FullInput;
+use word_bounds::rules::WordPredicate::{Any, In, ShorterThan};
+use word_bounds::rules::{ResolverProcessingRule, RuleSet};
+
+fn with_post(rules: Vec<ResolverProcessingRule>) -> RuleSet {
+    RuleSet {
+        post_pass_rules: rules,
+        ..RuleSet::default()
+    }
+}
+
+fn listed(words: &[&str]) -> Vec<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+type Cases = &'static [(&'static str, &'static [&'static str])];
+
+/// Each ruleset with inputs and the words every backend resolves them to.
+fn word_rules() -> Vec<(RuleSet, Cases)> {
+    let mut merged = with_post(vec![Attach(Word(In(listed(&["i"]))), Next)]);
+    merged.set_punct_chars("-_.,:;?! /");
+    vec![
+        (
+            with_post(vec![Remove(Word(In(listed(&["the", "of", "a"]))), All)]),
+            &[
+                // listed words match whatever their case
+                ("The_Lord_of_the_Rings", &["lord", "rings"]),
+                ("theory_of_everything", &["theory", "everything"]),
+            ],
+        ),
+        (with_post(vec![Remove(Word(ShorterThan(3)), All)]), &[("a_to_do_list", &["list"])]),
+        (
+            with_post(vec![Remove(Word(In(listed(&["get", "is"]))), Prepended(FullInput))]),
+            &[("getIsEnabled", &["enabled"]), ("isGetter", &["getter"])],
+        ),
+        (
+            merged,
+            &[
+                ("i/o error", &["io", "error"]),
+                // nothing to merge into
+                ("read_i", &["read", "i"]),
+            ],
+        ),
+        (with_post(vec![Remove(Word(Any), All)]), &[("gone_entirely", &[])]),
+    ]
+}
+
+#[test]
+fn charwalk_applies_word_rules() {
+    for (rules, cases) in word_rules() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Charwalk>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_fancy_regex")]
+fn fancy_regex_applies_word_rules() {
+    for (rules, cases) in word_rules() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<FancyRegex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "use_regex")]
+fn regex_applies_word_rules() {
+    for (rules, cases) in word_rules() {
+        for (input, expected) in cases {
+            let words = WordBoundResolver::<Regex>::resolve_with_rules(input, &rules);
+            assert_eq!(words, *expected, "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn a_merged_word_spans_both_it_was_made_from() {
+    let mut rules = with_post(vec![Attach(Word(In(listed(&["i"]))), Next)]);
+    rules.set_punct_chars("-_.,:;?! /");
+    let segments = WordBoundResolver::<Charwalk>::segments_with_rules("i/o error", &rules);
+    assert_eq!(segments[0].text, Cow::<str>::Owned("io".into()));
+    assert_eq!(segments[0].byte_range, 0..3);
+}
+
+#[test]
+fn a_predicate_reads_characters_rather_than_bytes() {
+    assert!(ShorterThan(3).matches("éé"));
+    assert!(!ShorterThan(2).matches("éé"));
+    assert!(In(listed(&["ÉTÉ"])).matches("été"));
+}